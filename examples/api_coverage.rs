@@ -0,0 +1,392 @@
+//! Compares the methods of the Web API to the ones rsteam implements
+//!
+//! `cargo run --example api_coverage` lists the methods that aren't
+//! implemented yet, implemented methods the API doesn't list anymore and
+//! methods missing from the README.
+//!
+//! `cargo run --example api_coverage -- generate ISteamUser GetFriendList`
+//! writes a stub module for the method in the same layout as the others.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use rsteam::steam_webapi_util::{ApiInterface, ApiMethod, ApiParameter};
+use rsteam::SteamClient;
+
+const ROOT: &str = env!("CARGO_MANIFEST_DIR");
+
+/// Interface name to method names
+type Methods = BTreeMap<String, BTreeSet<String>>;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let client = match env::var("STEAM_API_KEY") {
+        Ok(key) => SteamClient::with_api_key(&key),
+        Err(_) => {
+            println!("STEAM_API_KEY isn't set, methods only available with a key are left out");
+            SteamClient::new()
+        }
+    };
+    let interfaces = client.get_supported_api_list().await?;
+
+    let args: Vec<String> = env::args().skip(1).collect();
+    match args.iter().map(String::as_str).collect::<Vec<_>>().as_slice() {
+        [] => report(&interfaces)?,
+        ["generate", interface, method] => generate(&interfaces, interface, method)?,
+        _ => println!("Usage: api_coverage [generate <interface> <method>]"),
+    }
+
+    Ok(())
+}
+
+fn report(interfaces: &[ApiInterface]) -> std::io::Result<()> {
+    let implemented = implemented_methods(&Path::new(ROOT).join("src"))?;
+    let readme = readme_methods(&fs::read_to_string(Path::new(ROOT).join("README.md"))?);
+
+    let mut available = Methods::new();
+    for interface in interfaces {
+        let methods = interface.methods.iter().map(|method| method.name.clone());
+        available
+            .entry(interface.name.clone())
+            .or_default()
+            .extend(methods);
+    }
+
+    println!("Not implemented:");
+    for (interface, methods) in &available {
+        let missing: Vec<&String> = methods
+            .iter()
+            .filter(|method| !contains(&implemented, interface, method))
+            .collect();
+        if missing.is_empty() {
+            continue;
+        }
+        // Only list the interfaces that are partially implemented one by one
+        if implemented.contains_key(interface) {
+            for method in missing {
+                println!("    {}/{}", interface, method);
+            }
+        } else {
+            println!("    {} ({} methods)", interface, missing.len());
+        }
+    }
+
+    println!("Implemented but not listed by the API:");
+    for (interface, method) in flatten(&implemented) {
+        if !contains(&available, interface, method) {
+            println!("    {}/{}", interface, method);
+        }
+    }
+
+    println!("Implemented but missing from the README:");
+    for (interface, method) in flatten(&implemented) {
+        if !contains(&readme, interface, method) {
+            println!("    {}/{}", interface, method);
+        }
+    }
+
+    println!("In the README but not implemented:");
+    for (interface, method) in flatten(&readme) {
+        if !contains(&implemented, interface, method) {
+            println!("    {}/{}", interface, method);
+        }
+    }
+
+    Ok(())
+}
+
+fn contains(methods: &Methods, interface: &str, method: &str) -> bool {
+    methods
+        .get(interface)
+        .is_some_and(|methods| methods.contains(method))
+}
+
+fn flatten(methods: &Methods) -> impl Iterator<Item = (&String, &String)> {
+    methods
+        .iter()
+        .flat_map(|(interface, methods)| methods.iter().map(move |method| (interface, method)))
+}
+
+/// Finds the `const PATH: &str = "/Interface/Method/v1/"` constants of the modules
+///
+/// Paths of the community and store pages, like `/market/priceoverview/`,
+/// aren't Web API methods and are left out.
+fn implemented_methods(dir: &Path) -> std::io::Result<Methods> {
+    let mut methods = Methods::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            for (interface, names) in implemented_methods(&path)? {
+                methods.entry(interface).or_default().extend(names);
+            }
+            continue;
+        }
+        for line in fs::read_to_string(&path)?.lines() {
+            let Some(value) = line.trim().strip_prefix("const PATH: &str = \"/") else {
+                continue;
+            };
+            let mut parts = value.trim_end_matches("\";").split('/');
+            if let (Some(interface), Some(method), Some(_version)) =
+                (parts.next(), parts.next(), parts.next())
+            {
+                if !is_interface(interface) {
+                    continue;
+                }
+                methods
+                    .entry(interface.to_owned())
+                    .or_default()
+                    .insert(method.to_owned());
+            }
+        }
+    }
+    Ok(methods)
+}
+
+/// Parses the "Currently supported interfaces" list of the README
+fn readme_methods(readme: &str) -> Methods {
+    let mut methods = Methods::new();
+    let mut interface = None;
+    for line in readme.lines() {
+        if let Some(name) = line.strip_prefix("- ") {
+            // Only the Web API interfaces, not the community pages
+            interface = Some(name.trim().to_owned()).filter(|name| is_interface(name));
+        } else if let (Some(interface), Some(method)) = (&interface, line.trim().strip_prefix("- ")) {
+            methods
+                .entry(interface.clone())
+                .or_default()
+                .insert(method.trim().to_owned());
+        } else if line.trim().is_empty() {
+            interface = None;
+        }
+    }
+    methods
+}
+
+/// `ISteamUser`, `IPlayerService`, ...
+fn is_interface(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next() == Some('I') && chars.next().is_some_and(char::is_uppercase)
+}
+
+fn generate(interfaces: &[ApiInterface], interface: &str, method: &str) -> std::io::Result<()> {
+    let Some(api_method) = interfaces
+        .iter()
+        .filter(|api_interface| api_interface.name == interface)
+        .flat_map(|api_interface| &api_interface.methods)
+        .find(|api_method| api_method.name == method)
+    else {
+        println!("{}/{} isn't listed by the API", interface, method);
+        return Ok(());
+    };
+
+    let module = module_name(interface)?;
+    let file_name = snake_case(method);
+    let dir: PathBuf = Path::new(ROOT).join("src").join(&module);
+    let path = dir.join(format!("{}.rs", file_name));
+    if path.exists() {
+        println!("{} already exists", path.display());
+        return Ok(());
+    }
+
+    fs::create_dir_all(&dir)?;
+    fs::write(&path, stub(interface, api_method))?;
+    println!("Wrote {}", path.display());
+    println!("Add to src/{}/mod.rs:", module);
+    println!("    mod {};", file_name);
+    if !dir.join("mod.rs").exists() {
+        println!("and to src/lib.rs:");
+        println!("    #[cfg(feature = \"client\")]");
+        println!("    pub mod {};", module);
+    }
+    Ok(())
+}
+
+fn stub(interface: &str, method: &ApiMethod) -> String {
+    let type_name = method.name.trim_start_matches("Get").to_owned();
+    let fn_name = snake_case(&method.name);
+    let parameters: Vec<&ApiParameter> = method
+        .parameters
+        .iter()
+        .filter(|parameter| parameter.name != "key")
+        .collect();
+    let needs_key = method
+        .parameters
+        .iter()
+        .any(|parameter| parameter.name == "key" && !parameter.optional);
+
+    let mut arguments = String::new();
+    let mut todos = String::new();
+    let mut chain = vec![if needs_key {
+        ".add(\"key\", api_key)".to_owned()
+    } else {
+        ".add_optional(\"key\", self.api_key.as_ref())".to_owned()
+    }];
+    for parameter in &parameters {
+        if parameter.name.contains('[') {
+            todos.push_str(&format!("        // TODO: {}\n", parameter.name));
+            continue;
+        }
+        let name = identifier(&snake_case(&parameter.name));
+        let rust_type = rust_type(&parameter.parameter_type);
+        if parameter.optional {
+            arguments.push_str(&format!("        {}: Option<{}>,\n", name, rust_type));
+            chain.push(format!(".add_optional(\"{}\", {})", parameter.name, name));
+        } else {
+            arguments.push_str(&format!("        {}: {},\n", name, rust_type));
+            chain.push(format!(".add(\"{}\", {})", parameter.name, name));
+        }
+    }
+    let chain: String = chain
+        .iter()
+        .map(|call| format!("\n            {}", call))
+        .collect();
+
+    let key = if needs_key {
+        "        let api_key = self
+            .api_key
+            .as_ref()
+            .ok_or_else(|| Error::client(\"API key required\"))?;
+
+"
+    } else {
+        ""
+    };
+    let request = if method.http_method == "POST" {
+        "        let body = self.post_form(PATH, &params).await?;
+        let parsed = from_slice::<Response>(&body)?;"
+            .to_owned()
+    } else {
+        "        let uri = Uri::builder()
+            .scheme(\"https\")
+            .authority(AUTHORITY)
+            .path_and_query(format!(\"{}?{}\", PATH, params.encode()))
+            .build()?;
+
+        let response = self.client.get(uri).await?;
+        let body = response.into_body();
+        let parsed = from_slice::<Response>(&to_bytes(body).await?)?;"
+            .to_owned()
+    };
+    let imports = if method.http_method == "POST" {
+        "use crate::utils::{ResponseWrapper, Result};"
+    } else {
+        "use crate::utils::{ResponseWrapper, Result, AUTHORITY};"
+    };
+    let hyper_imports = if method.http_method == "POST" {
+        ""
+    } else {
+        "use hyper::body::to_bytes;\nuse hyper::Uri;\n\n"
+    };
+    let description = method
+        .description
+        .clone()
+        .unwrap_or_else(|| format!("Calls {}/{}", interface, method.name));
+
+    let error_import = if needs_key {
+        "use crate::error::Error;\n"
+    } else {
+        ""
+    };
+
+    format!(
+        "{error_import}use crate::params::Params;
+{imports}
+use crate::SteamClient;
+
+{hyper_imports}use serde::Deserialize;
+use serde_json::from_slice;
+
+const PATH: &str = \"/{interface}/{method}/v{version}/\";
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = \"serde-serialize\", derive(serde::Serialize))]
+pub struct {type_name} {{
+    // TODO: fields of the response
+}}
+
+type Response = ResponseWrapper<{type_name}>;
+
+impl SteamClient {{
+    /// {description}
+    pub async fn {fn_name}(
+        &self,
+{arguments}    ) -> Result<{type_name}> {{
+{key}{todos}        let params = Params::new(){chain};
+
+{request}
+
+        Ok(parsed.response)
+    }}
+}}
+",
+        method = method.name,
+        version = method.version,
+    )
+}
+
+/// The existing module of the interface, or its name in snake case
+///
+/// Names like `ISteamWebAPIUtil` don't split the same way as the existing
+/// module (`steam_webapi_util`), so the directories are compared without
+/// the underscores.
+fn module_name(interface: &str) -> std::io::Result<String> {
+    let module = snake_case(interface.trim_start_matches('I'));
+    let squashed = module.replace('_', "");
+    for entry in fs::read_dir(Path::new(ROOT).join("src"))? {
+        let path = entry?.path();
+        let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        if path.is_dir() && name.replace('_', "") == squashed {
+            return Ok(name.to_owned());
+        }
+    }
+    Ok(module)
+}
+
+/// Escapes parameter names that are Rust keywords, for example `type`
+fn identifier(name: &str) -> String {
+    const KEYWORDS: &[&str] = &[
+        "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do",
+        "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl", "in",
+        "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+        "return", "static", "struct", "trait", "true", "try", "type", "typeof", "unsafe",
+        "unsized", "use", "virtual", "where", "while", "yield",
+    ];
+    if KEYWORDS.contains(&name) {
+        format!("r#{}", name)
+    } else {
+        name.to_owned()
+    }
+}
+
+fn rust_type(api_type: &str) -> &'static str {
+    match api_type {
+        "uint32" => "u32",
+        "int32" => "i32",
+        "uint64" | "fixed64" => "u64",
+        "int64" => "i64",
+        "bool" => "bool",
+        "float" => "f32",
+        _ => "&str",
+    }
+}
+
+/// `GetSupportedAPIList` -> `get_supported_api_list`
+fn snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut snake = String::new();
+    for (i, c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let previous_lower = chars[i - 1].is_lowercase() || chars[i - 1].is_ascii_digit();
+            let next_lower = chars.get(i + 1).is_some_and(|next| next.is_lowercase());
+            if previous_lower || (chars[i - 1].is_uppercase() && next_lower) {
+                snake.push('_');
+            }
+        }
+        snake.extend(c.to_lowercase());
+    }
+    snake
+}
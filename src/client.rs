@@ -1,9 +1,20 @@
+use std::sync::Mutex;
+
+use crate::error::Error;
+use crate::params::Params;
+use crate::utils::{check_status, Result, AUTHORITY};
+
+use hyper::body::{to_bytes, Bytes};
 use hyper::client::HttpConnector;
-use hyper::Body;
+use hyper::header::CONTENT_TYPE;
 use hyper::Client as HyperClient;
+use hyper::{Body, Request, Uri};
 
 use hyper_rustls::{HttpsConnector, HttpsConnectorBuilder};
 
+use serde::de::DeserializeOwned;
+use serde_json::{from_slice, Value};
+
 /// Client to make API requests easily.
 ///
 /// Client can be constructed with or without an API key. Only subset
@@ -11,6 +22,9 @@ use hyper_rustls::{HttpsConnector, HttpsConnectorBuilder};
 pub struct SteamClient {
     pub(crate) client: HyperClient<HttpsConnector<HttpConnector>, Body>,
     pub(crate) api_key: Option<String>,
+    /// Seconds Steam's clock is ahead of the local one, see
+    /// [measure_clock_offset](SteamClient::measure_clock_offset)
+    pub(crate) clock_offset: Mutex<Option<i64>>,
 }
 
 impl Default for SteamClient {
@@ -29,6 +43,7 @@ impl SteamClient {
         SteamClient {
             client: HyperClient::builder().build::<_, Body>(https_connector),
             api_key: Some(key.to_owned()),
+            clock_offset: Mutex::new(None),
         }
     }
 
@@ -41,6 +56,158 @@ impl SteamClient {
         SteamClient {
             client: HyperClient::builder().build::<_, Body>(https_connector),
             api_key: None,
+            clock_offset: Mutex::new(None),
+        }
+    }
+
+    /// Calls an API method with a GET request and deserializes the response
+    ///
+    /// Can be used for methods the crate doesn't implement. The API key is
+    /// added to the params if the client has one and the params don't
+    /// already have a `key`. `interface` and `method` may only contain ASCII
+    /// letters, digits and underscores.
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), rsteam::error::Error> {
+    /// use rsteam::{Params, SteamClient};
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct PlayerCount {
+    ///     player_count: u32,
+    /// }
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Response {
+    ///     response: PlayerCount,
+    /// }
+    ///
+    /// let client = SteamClient::new();
+    /// let params = Params::new().add("appid", 730);
+    /// let response: Response = client
+    ///     .call("ISteamUserStats", "GetNumberOfCurrentPlayers", 1, params)
+    ///     .await?;
+    /// println!("{} players", response.response.player_count);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn call<T: DeserializeOwned>(
+        &self,
+        interface: &str,
+        method: &str,
+        version: u32,
+        params: Params,
+    ) -> Result<T> {
+        let path = method_path(interface, method, version)?;
+        let query = self.add_key(params).encode();
+        let uri = Uri::builder()
+            .scheme("https")
+            .authority(AUTHORITY)
+            .path_and_query(format!("{}?{}", path, query))
+            .build()?;
+
+        let response = self.client.get(uri).await?;
+        check_status(response.status())?;
+        let body = to_bytes(response.into_body()).await?;
+        Ok(from_slice(&body)?)
+    }
+
+    /// Same as [call](SteamClient::call) but returns the response as untyped json
+    pub async fn call_raw(
+        &self,
+        interface: &str,
+        method: &str,
+        version: u32,
+        params: Params,
+    ) -> Result<Value> {
+        self.call(interface, method, version, params).await
+    }
+
+    /// Sends a form-encoded POST request to an API method and returns the response body
+    ///
+    /// Can be used for methods the crate doesn't implement, for example
+    /// `client.post("ISteamUserAuth", "AuthenticateUserTicket", 1, params)`.
+    /// The API key is added the same way as with [call](SteamClient::call).
+    pub async fn post(&self, interface: &str, method: &str, version: u32, params: Params) -> Result<Bytes> {
+        let path = method_path(interface, method, version)?;
+        let params = self.add_key(params);
+        self.post_form(&path, &params).await
+    }
+
+    /// Adds the API key unless the params already have one
+    fn add_key(&self, params: Params) -> Params {
+        if params.contains("key") {
+            params
+        } else {
+            params.add_optional("key", self.api_key.as_ref())
+        }
+    }
+
+    /// Sends a form-encoded POST request to the API and returns the response body
+    pub(crate) async fn post_form(&self, path: &str, params: &Params) -> Result<Bytes> {
+        let uri = Uri::builder()
+            .scheme("https")
+            .authority(AUTHORITY)
+            .path_and_query(path)
+            .build()?;
+        let request = Request::post(uri)
+            .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+            .body(Body::from(params.encode()))?;
+
+        let response = self.client.request(request).await?;
+        check_status(response.status())?;
+        Ok(to_bytes(response.into_body()).await?)
+    }
+}
+
+/// Path of an API method, rejects names that would change the requested URL
+fn method_path(interface: &str, method: &str, version: u32) -> Result<String> {
+    for name in [interface, method] {
+        if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            return Err(Error::Client(format!("invalid interface or method name: {}", name)));
         }
     }
+    Ok(format!("/{}/{}/v{}/", interface, method, version))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio_test::block_on;
+
+    #[test]
+    fn keeps_own_key() {
+        let client = SteamClient::with_api_key("ABC");
+        let params = client.add_key(Params::new().add("appid", 730));
+        assert_eq!(params.encode(), "appid=730&key=ABC");
+        let params = client.add_key(Params::new().add("key", "DEF"));
+        assert_eq!(params.encode(), "key=DEF");
+        let params = SteamClient::new().add_key(Params::new());
+        assert!(params.is_empty());
+    }
+
+    #[test]
+    fn method_names() {
+        assert_eq!(
+            method_path("ISteamUser", "GetPlayerSummaries", 2).unwrap(),
+            "/ISteamUser/GetPlayerSummaries/v2/"
+        );
+        assert!(method_path("ISteamUser/../IPlayerService", "GetOwnedGames", 1).is_err());
+        assert!(method_path("ISteamUser", "GetPlayerSummaries?steamids=1", 2).is_err());
+        assert!(method_path("", "GetPlayerSummaries", 2).is_err());
+    }
+
+    #[test]
+    fn raw_player_count() {
+        let client = SteamClient::new();
+        let params = Params::new().add("appid", 730);
+        let response = block_on(client.call_raw(
+            "ISteamUserStats",
+            "GetNumberOfCurrentPlayers",
+            1,
+            params,
+        ))
+        .unwrap();
+        assert_eq!(response["response"]["result"], 1);
+    }
 }
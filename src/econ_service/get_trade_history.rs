@@ -0,0 +1,196 @@
+use crate::econ_service::TradeAsset;
+use crate::error::Error;
+use crate::params::Params;
+use crate::utils::{u64_from_str, ResponseWrapper, Result, AUTHORITY};
+use crate::{SteamClient, SteamID};
+
+use futures::stream::{self, Stream, TryStreamExt};
+use hyper::body::to_bytes;
+use hyper::Uri;
+
+use serde::Deserialize;
+use serde_json::from_slice;
+
+const PATH: &str = "/IEconService/GetTradeHistory/v1/";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize), serde(into = "u32"))]
+#[serde(from = "u32")]
+pub enum TradeStatus {
+    Init,
+    PreCommitted,
+    Committed,
+    Complete,
+    Failed,
+    PartialSupportRollback,
+    FullSupportRollback,
+    SupportRollbackSelective,
+    RollbackFailed,
+    RollbackAbandoned,
+    InEscrow,
+    EscrowRollback,
+    /// Status this crate doesn't know about yet
+    Unknown(u32),
+}
+
+impl From<u32> for TradeStatus {
+    fn from(status: u32) -> Self {
+        match status {
+            0 => TradeStatus::Init,
+            1 => TradeStatus::PreCommitted,
+            2 => TradeStatus::Committed,
+            3 => TradeStatus::Complete,
+            4 => TradeStatus::Failed,
+            5 => TradeStatus::PartialSupportRollback,
+            6 => TradeStatus::FullSupportRollback,
+            7 => TradeStatus::SupportRollbackSelective,
+            8 => TradeStatus::RollbackFailed,
+            9 => TradeStatus::RollbackAbandoned,
+            10 => TradeStatus::InEscrow,
+            11 => TradeStatus::EscrowRollback,
+            status => TradeStatus::Unknown(status),
+        }
+    }
+}
+
+impl From<TradeStatus> for u32 {
+    fn from(value: TradeStatus) -> Self {
+        match value {
+            TradeStatus::Init => 0,
+            TradeStatus::PreCommitted => 1,
+            TradeStatus::Committed => 2,
+            TradeStatus::Complete => 3,
+            TradeStatus::Failed => 4,
+            TradeStatus::PartialSupportRollback => 5,
+            TradeStatus::FullSupportRollback => 6,
+            TradeStatus::SupportRollbackSelective => 7,
+            TradeStatus::RollbackFailed => 8,
+            TradeStatus::RollbackAbandoned => 9,
+            TradeStatus::InEscrow => 10,
+            TradeStatus::EscrowRollback => 11,
+            TradeStatus::Unknown(status) => status,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct Trade {
+    #[serde(rename = "tradeid")]
+    #[serde(deserialize_with = "u64_from_str")]
+    pub id: u64,
+    #[serde(rename = "steamid_other")]
+    pub other_id: SteamID,
+    /// Unix timestamp of when the trade was started
+    pub time_init: u32,
+    /// Unix timestamp of when the trade leaves escrow
+    pub time_escrow_end: Option<u32>,
+    pub status: TradeStatus,
+    #[serde(default)]
+    pub assets_given: Vec<TradeAsset>,
+    #[serde(default)]
+    pub assets_received: Vec<TradeAsset>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct TradeHistory {
+    #[serde(default)]
+    pub trades: Vec<Trade>,
+    /// Are there more trades after the last one
+    #[serde(default)]
+    pub more: bool,
+    pub total_trades: Option<u32>,
+}
+
+type Response = ResponseWrapper<TradeHistory>;
+
+impl SteamClient {
+    /// Gets a page of the [TradeHistory] of the key owner, newest trades first
+    ///
+    /// Requires an API key. To get the next page pass the `time_init` and id
+    /// of the last [Trade] as `start_after`, or use
+    /// [get_trade_history_stream](SteamClient::get_trade_history_stream).
+    pub async fn get_trade_history(
+        &self,
+        max_trades: u32,
+        start_after: Option<(u32, u64)>,
+        include_failed: Option<bool>, // default false
+    ) -> Result<TradeHistory> {
+        let api_key = self
+            .api_key
+            .as_ref()
+            .ok_or_else(|| Error::client("API key required"))?;
+
+        let query = Params::new()
+            .add("key", api_key)
+            .add("max_trades", max_trades)
+            .add("include_total", true)
+            .add_optional("start_after_time", start_after.map(|(time, _)| time))
+            .add_optional("start_after_tradeid", start_after.map(|(_, trade_id)| trade_id))
+            .add_optional("include_failed", include_failed)
+            .encode();
+        let uri = Uri::builder()
+            .scheme("https")
+            .authority(AUTHORITY)
+            .path_and_query(format!("{}?{}", PATH, query))
+            .build()?;
+
+        let response = self.client.get(uri).await?;
+        let body = response.into_body();
+        let parsed = from_slice::<Response>(&to_bytes(body).await?)?;
+
+        Ok(parsed.response)
+    }
+
+    /// Returns a stream of all the [Trades](Trade) of the key owner, newest first
+    ///
+    /// Requires an API key. Trades are fetched `page_size` at a time.
+    pub fn get_trade_history_stream(
+        &self,
+        page_size: u32,
+        include_failed: Option<bool>,
+    ) -> impl Stream<Item = Result<Trade>> + '_ {
+        stream::try_unfold(Some(None), move |state: Option<Option<(u32, u64)>>| async move {
+            let start_after = match state {
+                Some(start_after) => start_after,
+                None => return Ok(None),
+            };
+            let history = self
+                .get_trade_history(page_size, start_after, include_failed)
+                .await?;
+            let next = match history.trades.last() {
+                Some(last) if history.more => Some(Some((last.time_init, last.id))),
+                _ => None,
+            };
+            Ok::<_, Error>(Some((history.trades, next)))
+        })
+        .map_ok(|trades| stream::iter(trades.into_iter().map(Ok)))
+        .try_flatten()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_history() {
+        let json = br#"{"response":{"total_trades":2,"more":true,"trades":[{
+            "tradeid":"3647483948364858293","steamid_other":"76561198061271782",
+            "time_init":1628265003,"status":3,
+            "assets_given":[{"appid":440,"contextid":"2","assetid":"10214236733","amount":"1",
+                "classid":"2675","instanceid":"11040578","new_assetid":"10214241000","new_contextid":"2"}]
+        }]}}"#;
+        let history = from_slice::<Response>(json).unwrap().response;
+        assert!(history.more);
+        assert_eq!(history.total_trades, Some(2));
+        let trade = &history.trades[0];
+        assert_eq!(trade.status, TradeStatus::Complete);
+        assert_eq!(trade.other_id, SteamID::from(76561198061271782));
+        assert_eq!(trade.assets_given[0].new_asset_id, Some(10214241000));
+        assert!(trade.assets_received.is_empty());
+        assert_eq!(TradeStatus::from(12), TradeStatus::Unknown(12));
+        assert_eq!(u32::from(TradeStatus::Init), 0);
+    }
+}
@@ -0,0 +1,63 @@
+use crate::econ_service::TradeOffer;
+use crate::error::Error;
+use crate::params::Params;
+use crate::utils::{ResponseWrapper, Result, AUTHORITY};
+use crate::SteamClient;
+
+use hyper::body::to_bytes;
+use hyper::Uri;
+
+use serde::Deserialize;
+use serde_json::from_slice;
+
+const PATH: &str = "/IEconService/GetTradeOffer/v1/";
+
+#[derive(Deserialize)]
+struct Offer {
+    offer: Option<TradeOffer>,
+}
+
+type Response = ResponseWrapper<Offer>;
+
+impl SteamClient {
+    /// Gets a single [TradeOffer] by its id
+    ///
+    /// Requires an API key. Only offers the key owner has sent or received
+    /// can be fetched.
+    pub async fn get_trade_offer(&self, trade_offer_id: u64) -> Result<TradeOffer> {
+        let api_key = self
+            .api_key
+            .as_ref()
+            .ok_or_else(|| Error::client("API key required"))?;
+
+        let query = Params::new()
+            .add("key", api_key)
+            .add("tradeofferid", trade_offer_id)
+            .encode();
+        let uri = Uri::builder()
+            .scheme("https")
+            .authority(AUTHORITY)
+            .path_and_query(format!("{}?{}", PATH, query))
+            .build()?;
+
+        let response = self.client.get(uri).await?;
+        let body = response.into_body();
+        let parsed = from_slice::<Response>(&to_bytes(body).await?)?;
+
+        parsed
+            .response
+            .offer
+            .ok_or_else(|| Error::client("no trade offer with provided id"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_missing_offer() {
+        let offer = from_slice::<Response>(br#"{"response":{}}"#).unwrap();
+        assert_eq!(offer.response.offer, None);
+    }
+}
@@ -0,0 +1,258 @@
+use std::result::Result as StdResult;
+
+use crate::error::Error;
+use crate::params::Params;
+use crate::utils::{u64_from_str, ResponseWrapper, Result, AUTHORITY};
+use crate::{SteamClient, SteamID};
+
+use hyper::body::to_bytes;
+use hyper::Uri;
+
+use serde::{Deserialize, Deserializer};
+use serde_aux::field_attributes::deserialize_option_number_from_string;
+use serde_json::from_slice;
+
+const PATH: &str = "/IEconService/GetTradeOffers/v1/";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize), serde(into = "u32"))]
+#[serde(from = "u32")]
+pub enum TradeOfferState {
+    Invalid,
+    Active,
+    Accepted,
+    Countered,
+    Expired,
+    Canceled,
+    Declined,
+    InvalidItems,
+    CreatedNeedsConfirmation,
+    CanceledBySecondFactor,
+    InEscrow,
+    /// State this crate doesn't know about yet
+    Unknown(u32),
+}
+
+impl From<u32> for TradeOfferState {
+    fn from(state: u32) -> Self {
+        match state {
+            1 => TradeOfferState::Invalid,
+            2 => TradeOfferState::Active,
+            3 => TradeOfferState::Accepted,
+            4 => TradeOfferState::Countered,
+            5 => TradeOfferState::Expired,
+            6 => TradeOfferState::Canceled,
+            7 => TradeOfferState::Declined,
+            8 => TradeOfferState::InvalidItems,
+            9 => TradeOfferState::CreatedNeedsConfirmation,
+            10 => TradeOfferState::CanceledBySecondFactor,
+            11 => TradeOfferState::InEscrow,
+            state => TradeOfferState::Unknown(state),
+        }
+    }
+}
+
+impl From<TradeOfferState> for u32 {
+    fn from(value: TradeOfferState) -> Self {
+        match value {
+            TradeOfferState::Invalid => 1,
+            TradeOfferState::Active => 2,
+            TradeOfferState::Accepted => 3,
+            TradeOfferState::Countered => 4,
+            TradeOfferState::Expired => 5,
+            TradeOfferState::Canceled => 6,
+            TradeOfferState::Declined => 7,
+            TradeOfferState::InvalidItems => 8,
+            TradeOfferState::CreatedNeedsConfirmation => 9,
+            TradeOfferState::CanceledBySecondFactor => 10,
+            TradeOfferState::InEscrow => 11,
+            TradeOfferState::Unknown(state) => state,
+        }
+    }
+}
+
+/// Item in a trade offer or a trade
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct TradeAsset {
+    #[serde(rename = "appid")]
+    pub app_id: u32,
+    #[serde(rename = "contextid")]
+    #[serde(deserialize_with = "u64_from_str")]
+    pub context_id: u64,
+    #[serde(rename = "assetid")]
+    #[serde(deserialize_with = "u64_from_str")]
+    pub asset_id: u64,
+    #[serde(rename = "classid")]
+    #[serde(deserialize_with = "u64_from_str")]
+    pub class_id: u64,
+    #[serde(rename = "instanceid")]
+    #[serde(deserialize_with = "u64_from_str")]
+    pub instance_id: u64,
+    #[serde(deserialize_with = "u64_from_str")]
+    pub amount: u64,
+    /// The item is no longer in the inventory it was offered from
+    #[serde(default)]
+    pub missing: bool,
+    /// Asset id in the new owners inventory, only in completed trades
+    #[serde(rename = "new_assetid")]
+    #[serde(default, deserialize_with = "deserialize_option_number_from_string")]
+    pub new_asset_id: Option<u64>,
+    /// Context id in the new owners inventory, only in completed trades
+    #[serde(rename = "new_contextid")]
+    #[serde(default, deserialize_with = "deserialize_option_number_from_string")]
+    pub new_context_id: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct TradeOffer {
+    #[serde(rename = "tradeofferid")]
+    #[serde(deserialize_with = "u64_from_str")]
+    pub id: u64,
+    /// 32-bit account id of the other party, see [other_id](TradeOffer::other_id)
+    #[serde(rename = "accountid_other")]
+    pub account_id_other: u32,
+    #[serde(default)]
+    pub message: String,
+    /// Unix timestamp of when the offer expires
+    pub expiration_time: u32,
+    #[serde(rename = "trade_offer_state")]
+    pub state: TradeOfferState,
+    #[serde(default)]
+    pub items_to_give: Vec<TradeAsset>,
+    #[serde(default)]
+    pub items_to_receive: Vec<TradeAsset>,
+    pub is_our_offer: bool,
+    /// Unix timestamp
+    pub time_created: u32,
+    /// Unix timestamp
+    pub time_updated: u32,
+    /// Id of the trade, only available for accepted offers
+    #[serde(rename = "tradeid")]
+    #[serde(default, deserialize_with = "deserialize_option_number_from_string")]
+    pub trade_id: Option<u64>,
+    #[serde(default)]
+    pub from_real_time_trade: bool,
+    /// Unix timestamp of when the offer leaves escrow, 0 if not in escrow
+    #[serde(default)]
+    pub escrow_end_date: u32,
+}
+
+impl TradeOffer {
+    /// [SteamID] of the other party of the offer
+    pub fn other_id(&self) -> SteamID {
+        SteamID::new(1, 1, 1, self.account_id_other)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct TradeOffers {
+    #[serde(rename = "trade_offers_sent")]
+    #[serde(default)]
+    pub sent: Vec<TradeOffer>,
+    #[serde(rename = "trade_offers_received")]
+    #[serde(default)]
+    pub received: Vec<TradeOffer>,
+    /// Cursor of the next page, `None` after the last page
+    #[serde(default, deserialize_with = "cursor_from_int")]
+    pub next_cursor: Option<u32>,
+}
+
+/// The last page has `0` as its `next_cursor`
+fn cursor_from_int<'de, D>(deserializer: D) -> StdResult<Option<u32>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(Option::<u32>::deserialize(deserializer)?.filter(|cursor| *cursor != 0))
+}
+
+type Response = ResponseWrapper<TradeOffers>;
+
+impl SteamClient {
+    /// Gets the sent and/or received [TradeOffers]
+    ///
+    /// Requires an API key. By default only active offers are returned,
+    /// setting `active_only` to `false` also returns offers that were active
+    /// after `historical_cutoff` (unix timestamp). Offers are paged, pass
+    /// [next_cursor](TradeOffers::next_cursor) as `cursor` to get the next page.
+    /// Item descriptions can be fetched with
+    /// [get_asset_class_info](SteamClient::get_asset_class_info).
+    pub async fn get_trade_offers(
+        &self,
+        sent: bool,
+        received: bool,
+        active_only: Option<bool>,     // default true
+        historical_cutoff: Option<u32>, // default none
+        cursor: Option<u32>,
+    ) -> Result<TradeOffers> {
+        let api_key = self
+            .api_key
+            .as_ref()
+            .ok_or_else(|| Error::client("API key required"))?;
+
+        if !sent && !received {
+            return Err(Error::client("either sent or received offers required"));
+        }
+
+        let query = Params::new()
+            .add("key", api_key)
+            .add("get_sent_offers", sent)
+            .add("get_received_offers", received)
+            .add("active_only", active_only.unwrap_or(true))
+            .add_optional("time_historical_cutoff", historical_cutoff)
+            .add_optional("cursor", cursor)
+            .encode();
+        let uri = Uri::builder()
+            .scheme("https")
+            .authority(AUTHORITY)
+            .path_and_query(format!("{}?{}", PATH, query))
+            .build()?;
+
+        let response = self.client.get(uri).await?;
+        let body = response.into_body();
+        let parsed = from_slice::<Response>(&to_bytes(body).await?)?;
+
+        Ok(parsed.response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_trade_offers() {
+        let json = br#"{"response":{
+            "trade_offers_sent":[{
+                "tradeofferid":"4736459877","accountid_other":101006054,"message":"hi",
+                "expiration_time":1629474513,"trade_offer_state":3,
+                "items_to_give":[{"appid":440,"contextid":"2","assetid":"10214236733","classid":"2675",
+                    "instanceid":"11040578","amount":"1","missing":true}],
+                "is_our_offer":true,"time_created":1628264913,"time_updated":1628265003,
+                "tradeid":"3647483948364858293","from_real_time_trade":false,"escrow_end_date":0,
+                "confirmation_method":2}],
+            "next_cursor":0}}"#;
+        let offers = from_slice::<Response>(json).unwrap().response;
+        assert!(offers.received.is_empty());
+        let offer = &offers.sent[0];
+        assert_eq!(offer.state, TradeOfferState::Accepted);
+        assert_eq!(offer.other_id(), SteamID::from(76561198061271782));
+        assert_eq!(offer.trade_id, Some(3647483948364858293));
+        assert!(offer.items_to_give[0].missing);
+        assert!(offer.items_to_receive.is_empty());
+        assert_eq!(offers.next_cursor, None);
+
+        let json = br#"{"response":{
+            "trade_offers_received":[{
+                "tradeofferid":"4736459878","accountid_other":101006054,"expiration_time":1629474513,
+                "trade_offer_state":12,"is_our_offer":false,"time_created":1628264913,
+                "time_updated":1628265003}],
+            "next_cursor":100}}"#;
+        let offers = from_slice::<Response>(json).unwrap().response;
+        assert_eq!(offers.received[0].state, TradeOfferState::Unknown(12));
+        assert_eq!(u32::from(offers.received[0].state), 12);
+        assert_eq!(offers.next_cursor, Some(100));
+    }
+}
@@ -0,0 +1,81 @@
+use crate::error::Error;
+use crate::params::Params;
+use crate::utils::{ResponseWrapper, Result, AUTHORITY};
+use crate::SteamClient;
+
+use hyper::body::to_bytes;
+use hyper::Uri;
+
+use serde::Deserialize;
+use serde_json::from_slice;
+
+const PATH: &str = "/IEconService/GetTradeOffersSummary/v1/";
+
+/// Counts of the trade offers of the key owner
+///
+/// New and updated counts are relative to the `time_last_visit`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct TradeOffersSummary {
+    pub pending_received_count: u32,
+    pub new_received_count: u32,
+    pub updated_received_count: u32,
+    pub historical_received_count: u32,
+    pub pending_sent_count: u32,
+    pub newly_accepted_sent_count: u32,
+    pub updated_sent_count: u32,
+    pub historical_sent_count: u32,
+    #[serde(default)]
+    pub escrow_received_count: u32,
+    #[serde(default)]
+    pub escrow_sent_count: u32,
+}
+
+type Response = ResponseWrapper<TradeOffersSummary>;
+
+impl SteamClient {
+    /// Gets the [TradeOffersSummary] of the key owner
+    ///
+    /// Requires an API key. `time_last_visit` is an unix timestamp.
+    pub async fn get_trade_offers_summary(
+        &self,
+        time_last_visit: Option<u32>,
+    ) -> Result<TradeOffersSummary> {
+        let api_key = self
+            .api_key
+            .as_ref()
+            .ok_or_else(|| Error::client("API key required"))?;
+
+        let query = Params::new()
+            .add("key", api_key)
+            .add_optional("time_last_visit", time_last_visit)
+            .encode();
+        let uri = Uri::builder()
+            .scheme("https")
+            .authority(AUTHORITY)
+            .path_and_query(format!("{}?{}", PATH, query))
+            .build()?;
+
+        let response = self.client.get(uri).await?;
+        let body = response.into_body();
+        let parsed = from_slice::<Response>(&to_bytes(body).await?)?;
+
+        Ok(parsed.response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_summary() {
+        let json = br#"{"response":{"pending_received_count":1,"new_received_count":0,
+            "updated_received_count":0,"historical_received_count":12,"pending_sent_count":2,
+            "newly_accepted_sent_count":0,"updated_sent_count":1,"historical_sent_count":30,
+            "escrow_received_count":0,"escrow_sent_count":0}}"#;
+        let summary = from_slice::<Response>(json).unwrap().response;
+        assert_eq!(summary.pending_sent_count, 2);
+        assert_eq!(summary.historical_sent_count, 30);
+    }
+}
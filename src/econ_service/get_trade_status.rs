@@ -0,0 +1,71 @@
+use crate::econ_service::Trade;
+use crate::error::Error;
+use crate::params::Params;
+use crate::utils::{ResponseWrapper, Result, AUTHORITY};
+use crate::SteamClient;
+
+use hyper::body::to_bytes;
+use hyper::Uri;
+
+use serde::Deserialize;
+use serde_json::from_slice;
+
+const PATH: &str = "/IEconService/GetTradeStatus/v1/";
+
+#[derive(Deserialize)]
+struct Trades {
+    #[serde(default)]
+    trades: Vec<Trade>,
+}
+
+type Response = ResponseWrapper<Trades>;
+
+impl SteamClient {
+    /// Gets the status of a completed or failed [Trade]
+    ///
+    /// Requires an API key. The trade id is available in accepted
+    /// [TradeOffers](crate::econ_service::TradeOffer).
+    pub async fn get_trade_status(&self, trade_id: u64) -> Result<Trade> {
+        let api_key = self
+            .api_key
+            .as_ref()
+            .ok_or_else(|| Error::client("API key required"))?;
+
+        let query = Params::new()
+            .add("key", api_key)
+            .add("tradeid", trade_id)
+            .encode();
+        let uri = Uri::builder()
+            .scheme("https")
+            .authority(AUTHORITY)
+            .path_and_query(format!("{}?{}", PATH, query))
+            .build()?;
+
+        let response = self.client.get(uri).await?;
+        let body = response.into_body();
+        let parsed = from_slice::<Response>(&to_bytes(body).await?)?;
+
+        parsed
+            .response
+            .trades
+            .into_iter()
+            .next()
+            .ok_or_else(|| Error::client("no trade with provided id"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::econ_service::TradeStatus;
+
+    #[test]
+    fn parse_status() {
+        let json = br#"{"response":{"trades":[{"tradeid":"3647483948364858293",
+            "steamid_other":"76561198061271782","time_init":1628265003,"time_escrow_end":1628869803,
+            "status":10}]}}"#;
+        let trades = from_slice::<Response>(json).unwrap().response.trades;
+        assert_eq!(trades[0].status, TradeStatus::InEscrow);
+        assert_eq!(trades[0].time_escrow_end, Some(1628869803));
+    }
+}
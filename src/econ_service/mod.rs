@@ -0,0 +1,13 @@
+//! Implementations for the IEconService interface
+//!
+//! All methods require an API key and operate on the trades of the key owner.
+
+mod get_trade_history;
+mod get_trade_offer;
+mod get_trade_offers;
+mod get_trade_offers_summary;
+mod get_trade_status;
+
+pub use get_trade_history::{Trade, TradeHistory, TradeStatus};
+pub use get_trade_offers::{TradeAsset, TradeOffer, TradeOfferState, TradeOffers};
+pub use get_trade_offers_summary::TradeOffersSummary;
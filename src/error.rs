@@ -1,3 +1,5 @@
+use crate::steam_id::SteamError;
+
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
     #[error("error while parsin json: {0}")]
@@ -8,6 +10,16 @@ pub enum Error {
     HttpClient(#[from] hyper::Error),
     #[error("error with http: {0}")]
     Http(#[from] hyper::http::Error),
+    #[error("error while parsing steam id: {0}")]
+    SteamID(#[from] SteamError),
+    #[error("profile is private")]
+    PrivateProfile,
+    #[error("app has no stats")]
+    NoStats,
+    #[error("inventory is private")]
+    PrivateInventory,
+    #[error("rate limited by the API")]
+    RateLimited,
     #[error("client error: {0}")]
     Client(String),
 }
@@ -1,6 +1,5 @@
 use crate::error::Error;
-use crate::steam_id::SteamID3;
-use crate::utils::Result;
+use crate::utils::{Result, COMMUNITY_AUTHORITY};
 use crate::{SteamClient, SteamID};
 
 use hyper::body::to_bytes;
@@ -9,9 +8,8 @@ use hyper::http::uri::{Scheme, Uri};
 use serde::Deserialize;
 use serde_xml_rs::from_str;
 
-const AUTHORITY: &str = "steamcommunity.com";
-
-#[derive(Deserialize, Debug)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
 pub struct Group {
     #[serde(rename = "groupID64")]
     pub id: SteamID,
@@ -21,7 +19,8 @@ pub struct Group {
     pub member_count: u32,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
 pub struct GroupDetails {
     #[serde(rename = "groupName")]
     pub name: String,
@@ -55,14 +54,13 @@ impl SteamClient {
         group_id: &SteamID,
         //include_members: Option<bool>,
     ) -> Result<Group> {
-        let gid = SteamID3::from(*group_id).to_string();
-        let path = format!(
-            "/gid/{}/memberslistxml?xml=1",
-            gid[5..gid.len() - 1].to_owned()
-        );
+        if !group_id.is_valid() || !group_id.is_clan() {
+            return Err(Error::client("SteamID is not a valid group id"));
+        }
+        let path = format!("/gid/{}/memberslistxml?xml=1", group_id.account_id());
         let uri = Uri::builder()
             .scheme(Scheme::HTTPS)
-            .authority(AUTHORITY)
+            .authority(COMMUNITY_AUTHORITY)
             .path_and_query(path)
             .build()?;
 
@@ -0,0 +1,287 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::error::Error;
+use crate::params::Params;
+use crate::steam_economy::AssetDescription;
+use crate::utils::{
+    bool_from_int, check_ids, check_status, u64_from_str, Result, COMMUNITY_AUTHORITY,
+};
+use crate::{SteamClient, SteamID};
+
+use hyper::body::to_bytes;
+use hyper::http::uri::{Scheme, Uri};
+use hyper::StatusCode;
+
+use futures::stream::{self, Stream, TryStreamExt};
+use serde::Deserialize;
+use serde_json::from_slice;
+
+/// Maximum amount of items the community returns per page
+const PAGE_SIZE: u32 = 2000;
+/// Delay between page requests to stay under community rate limits
+const PAGE_DELAY: Duration = Duration::from_millis(1500);
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct InventoryTag {
+    pub category: String,
+    pub internal_name: String,
+    pub localized_category_name: String,
+    pub localized_tag_name: String,
+    pub color: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct InventoryDescription {
+    #[serde(rename = "classid")]
+    #[serde(deserialize_with = "u64_from_str")]
+    pub class_id: u64,
+    #[serde(rename = "instanceid")]
+    #[serde(deserialize_with = "u64_from_str")]
+    pub instance_id: u64,
+    pub name: String,
+    #[serde(default)]
+    pub market_name: String,
+    #[serde(default)]
+    pub market_hash_name: String,
+    #[serde(rename = "type")]
+    #[serde(default)]
+    pub item_type: String,
+    #[serde(default)]
+    pub icon_url: String,
+    pub icon_url_large: Option<String>,
+    pub name_color: Option<String>,
+    #[serde(default)]
+    pub background_color: String,
+    #[serde(deserialize_with = "bool_from_int")]
+    pub tradable: bool,
+    #[serde(deserialize_with = "bool_from_int")]
+    pub marketable: bool,
+    #[serde(deserialize_with = "bool_from_int")]
+    pub commodity: bool,
+    #[serde(default)]
+    pub descriptions: Vec<AssetDescription>,
+    #[serde(default)]
+    pub tags: Vec<InventoryTag>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct InventoryItem {
+    pub asset_id: u64,
+    pub amount: u64,
+    pub description: InventoryDescription,
+}
+
+#[derive(Deserialize)]
+struct Asset {
+    #[serde(rename = "assetid")]
+    #[serde(deserialize_with = "u64_from_str")]
+    asset_id: u64,
+    #[serde(rename = "classid")]
+    #[serde(deserialize_with = "u64_from_str")]
+    class_id: u64,
+    #[serde(rename = "instanceid")]
+    #[serde(deserialize_with = "u64_from_str")]
+    instance_id: u64,
+    #[serde(deserialize_with = "u64_from_str")]
+    amount: u64,
+}
+
+#[derive(Deserialize)]
+struct Page {
+    success: u32,
+    #[serde(default)]
+    assets: Vec<Asset>,
+    #[serde(default)]
+    descriptions: Vec<InventoryDescription>,
+    #[serde(default)]
+    more_items: u32,
+    last_assetid: Option<String>,
+}
+
+impl Page {
+    /// Joins the assets with their descriptions and returns the asset id
+    /// to continue from if there are more items
+    fn into_items(self) -> Result<(Vec<InventoryItem>, Option<u64>)> {
+        if self.success != 1 {
+            return Err(Error::client("request failed"));
+        }
+        let descriptions: HashMap<(u64, u64), InventoryDescription> = self
+            .descriptions
+            .into_iter()
+            .map(|d| ((d.class_id, d.instance_id), d))
+            .collect();
+        let items = self
+            .assets
+            .into_iter()
+            .map(|asset| {
+                let description = descriptions
+                    .get(&(asset.class_id, asset.instance_id))
+                    .cloned()
+                    .ok_or_else(|| Error::client("asset is missing its description"))?;
+                Ok(InventoryItem {
+                    asset_id: asset.asset_id,
+                    amount: asset.amount,
+                    description,
+                })
+            })
+            .collect::<Result<Vec<InventoryItem>>>()?;
+
+        let next = match (self.more_items, self.last_assetid) {
+            (1, Some(last)) => Some(
+                last.parse::<u64>()
+                    .map_err(|_| Error::client("returned last_assetid is invalid"))?,
+            ),
+            _ => None,
+        };
+        Ok((items, next))
+    }
+}
+
+impl SteamClient {
+    /// Returns a stream of the [InventoryItems](InventoryItem) in the users inventory
+    ///
+    /// Works without an API key. Items are fetched in pages of 2000 and
+    /// there's a short delay between the pages to respect the community rate
+    /// limits. Returns [Error::PrivateInventory] if the inventory isn't public
+    /// and [Error::RateLimited] if the community refuses the request.
+    /// For example CS2 items are in app 730 context 2. Names and descriptions
+    /// are localized to the given language, by default english is used.
+    pub fn get_inventory(
+        &self,
+        id: &SteamID,
+        app_id: u32,
+        context_id: u64,
+        language: Option<&str>,
+    ) -> impl Stream<Item = Result<InventoryItem>> + '_ {
+        let id = *id;
+        stream::try_unfold(
+            (language.map(str::to_owned), Some(None)),
+            move |(language, state): (Option<String>, Option<Option<u64>>)| async move {
+                let start = match state {
+                    Some(start) => start,
+                    None => return Ok(None),
+                };
+                match start {
+                    // The id only needs to be checked before the first page
+                    None => check_ids(&[id])?,
+                    Some(_) => tokio::time::sleep(PAGE_DELAY).await,
+                }
+                let path = inventory_path(&id, app_id, context_id, language.as_deref(), start);
+                let (items, next) = self.fetch_inventory_page(path).await?;
+                Ok::<_, Error>(Some((items, (language, next.map(Some)))))
+            },
+        )
+        .map_ok(|items| stream::iter(items.into_iter().map(Ok)))
+        .try_flatten()
+    }
+
+    async fn fetch_inventory_page(
+        &self,
+        path: String,
+    ) -> Result<(Vec<InventoryItem>, Option<u64>)> {
+        let uri = Uri::builder()
+            .scheme(Scheme::HTTPS)
+            .authority(COMMUNITY_AUTHORITY)
+            .path_and_query(path)
+            .build()?;
+
+        let raw_response = self.client.get(uri).await?;
+        if raw_response.status() == StatusCode::FORBIDDEN {
+            return Err(Error::PrivateInventory);
+        }
+        check_status(raw_response.status())?;
+        let page: Page = from_slice(&to_bytes(raw_response.into_body()).await?)?;
+
+        page.into_items()
+    }
+}
+
+/// Path and query of the inventory page starting after `start_asset_id`
+fn inventory_path(
+    id: &SteamID,
+    app_id: u32,
+    context_id: u64,
+    language: Option<&str>,
+    start_asset_id: Option<u64>,
+) -> String {
+    let query = Params::new()
+        .add_optional("l", language)
+        .add("count", PAGE_SIZE)
+        .add_optional("start_assetid", start_asset_id)
+        .encode();
+    format!("/inventory/{}/{}/{}?{}", id, app_id, context_id, query)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio_test::block_on;
+
+    #[test]
+    fn joins_descriptions() {
+        let json = br#"{
+            "assets":[
+                {"appid":730,"contextid":"2","assetid":"101","classid":"5","instanceid":"0","amount":"1"},
+                {"appid":730,"contextid":"2","assetid":"102","classid":"6","instanceid":"7","amount":"3"}
+            ],
+            "descriptions":[
+                {"appid":730,"classid":"5","instanceid":"0","currency":0,"background_color":"","icon_url":"a",
+                    "tradable":1,"name":"Case","market_hash_name":"Case","market_name":"Case","type":"Container",
+                    "marketable":1,"commodity":1,"tags":[{"category":"Type","internal_name":"CSGO_Type_WeaponCase",
+                    "localized_category_name":"Type","localized_tag_name":"Container"}]},
+                {"appid":730,"classid":"6","instanceid":"7","currency":0,"background_color":"","icon_url":"b",
+                    "descriptions":[{"type":"html","value":"Sticker"}],
+                    "tradable":0,"name":"Sticker","type":"Sticker","marketable":0,"commodity":0}
+            ],
+            "more_items":1,"last_assetid":"102","total_inventory_count":5000,"success":1,"rwgrsn":-2}"#;
+        let (items, next) = from_slice::<Page>(json).unwrap().into_items().unwrap();
+        assert_eq!(next, Some(102));
+        assert_eq!(items[0].description.name, "Case");
+        assert_eq!(items[1].amount, 3);
+        assert!(!items[1].description.tradable);
+
+        let last = br#"{"total_inventory_count":0,"success":1,"rwgrsn":-2}"#;
+        let (items, next) = from_slice::<Page>(last).unwrap().into_items().unwrap();
+        assert!(items.is_empty());
+        assert_eq!(next, None);
+    }
+
+    #[test]
+    fn page_paths() {
+        let id = SteamID::from(76561198061271782);
+        assert_eq!(
+            inventory_path(&id, 730, 2, None, None),
+            "/inventory/76561198061271782/730/2?count=2000"
+        );
+        assert_eq!(
+            inventory_path(&id, 730, 2, Some("finnish"), Some(102)),
+            "/inventory/76561198061271782/730/2?l=finnish&count=2000&start_assetid=102"
+        );
+    }
+
+    #[test]
+    fn invalid_id() {
+        let client = SteamClient::new();
+        let items: Result<Vec<InventoryItem>> = block_on(
+            client
+                .get_inventory(&SteamID::from(0), 730, 2, None)
+                .try_collect(),
+        );
+        assert!(
+            matches!(items, Err(Error::Client(message)) if message.starts_with("invalid SteamID"))
+        );
+    }
+
+    #[test]
+    fn fetch_inventory() {
+        let client = SteamClient::new();
+        let id = SteamID::from(76561198061271782);
+        let items: Vec<InventoryItem> =
+            block_on(client.get_inventory(&id, 730, 2, None).try_collect()).unwrap();
+        assert!(!items.is_empty());
+    }
+}
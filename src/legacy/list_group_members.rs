@@ -1,6 +1,5 @@
 use crate::error::Error;
-use crate::steam_id::SteamID3;
-use crate::utils::Result;
+use crate::utils::{Result, COMMUNITY_AUTHORITY};
 use crate::{SteamClient, SteamID};
 
 use hyper::body::to_bytes;
@@ -11,8 +10,6 @@ use serde_xml_rs::from_str;
 
 use futures::future::try_join_all;
 
-const AUTHORITY: &str = "steamcommunity.com";
-
 #[derive(Deserialize, Debug)]
 struct Members {
     #[serde(rename = "steamID64")]
@@ -30,9 +27,6 @@ struct Group {
     members: Members,
 }
 
-#[derive(Deserialize, Debug)]
-struct GroupDetails {}
-
 impl SteamClient {
     /// Returns info about group by its id
     ///
@@ -42,13 +36,13 @@ impl SteamClient {
     /// Thats why caching the result is recommended instead of calling the function
     /// multiple times for same group.
     pub async fn list_group_members(&self, group_id: &SteamID) -> Result<Vec<SteamID>> {
-        let gid = SteamID3::from(*group_id).to_string();
-        let legacy_id = gid[5..gid.len() - 1].to_owned();
-
-        let path = format!("/gid/{}/memberslistxml?xml=1", legacy_id);
+        if !group_id.is_valid() || !group_id.is_clan() {
+            return Err(Error::client("SteamID is not a valid group id"));
+        }
+        let path = format!("/gid/{}/memberslistxml?xml=1", group_id.account_id());
         let uri = Uri::builder()
             .scheme(Scheme::HTTPS)
-            .authority(AUTHORITY)
+            .authority(COMMUNITY_AUTHORITY)
             .path_and_query(path)
             .build()?;
 
@@ -75,13 +69,14 @@ impl SteamClient {
     }
 
     async fn fetch_page(&self, group_id: &SteamID, page: u32) -> Result<Vec<SteamID>> {
-        let gid = SteamID3::from(*group_id).to_string();
-        let legacy_id = gid[5..gid.len() - 1].to_owned();
-
-        let path = format!("/gid/{}/memberslistxml?xml=1&p={}", legacy_id, page);
+        let path = format!(
+            "/gid/{}/memberslistxml?xml=1&p={}",
+            group_id.account_id(),
+            page
+        );
         let uri = Uri::builder()
             .scheme(Scheme::HTTPS)
-            .authority(AUTHORITY)
+            .authority(COMMUNITY_AUTHORITY)
             .path_and_query(path)
             .build()?;
 
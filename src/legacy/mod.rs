@@ -1,4 +1,6 @@
 mod get_group_summary;
+mod get_inventory;
 mod list_group_members;
 
 pub use get_group_summary::Group;
+pub use get_inventory::{InventoryDescription, InventoryItem, InventoryTag};
@@ -4,19 +4,57 @@
 //! since it's build on top of [hyper]
 //!
 //! [hyper]: https://hyper.rs/
+//!
+//! The HTTP client is behind the default `client` feature. Without it only
+//! the [steam_id] module is available, and without the `std` feature the
+//! crate is `no_std` (requires `alloc`).
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
 
+#[cfg(feature = "client")]
 mod client;
+#[cfg(feature = "client")]
 pub mod error;
-#[macro_use]
-mod macros;
+#[cfg(feature = "client")]
+pub mod econ_service;
+#[cfg(feature = "client")]
 pub mod legacy;
+#[cfg(feature = "client")]
+pub mod market;
+#[cfg(feature = "client")]
+mod params;
+#[cfg(feature = "client")]
 pub mod player_service;
+#[cfg(feature = "client")]
+pub mod published_file_service;
+#[cfg(feature = "client")]
 pub mod steam_apps;
+#[cfg(feature = "client")]
+pub mod steam_economy;
 pub mod steam_id;
+#[cfg(feature = "client")]
 pub mod steam_news;
+#[cfg(feature = "client")]
+pub mod steam_remote_storage;
+#[cfg(feature = "client")]
 pub mod steam_user;
+#[cfg(feature = "client")]
 pub mod steam_user_stats;
+#[cfg(feature = "client")]
+pub mod steam_webapi_util;
+#[cfg(feature = "client")]
+pub mod store;
+#[cfg(feature = "client")]
+pub mod store_service;
+#[cfg(feature = "time")]
+pub mod timestamp;
+#[cfg(feature = "client")]
 mod utils;
 
+#[cfg(feature = "client")]
 pub use client::SteamClient;
+#[cfg(feature = "client")]
+pub use params::Params;
 pub use steam_id::SteamID;
@@ -0,0 +1,111 @@
+use std::fmt;
+
+/// Currencies supported by the market, the values are the Steam currency ids
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub enum Currency {
+    #[default]
+    USD = 1,
+    GBP = 2,
+    EUR = 3,
+    CHF = 4,
+    RUB = 5,
+    PLN = 6,
+    BRL = 7,
+    JPY = 8,
+    NOK = 9,
+    IDR = 10,
+    MYR = 11,
+    PHP = 12,
+    SGD = 13,
+    THB = 14,
+    VND = 15,
+    KRW = 16,
+    TRY = 17,
+    UAH = 18,
+    MXN = 19,
+    CAD = 20,
+    AUD = 21,
+    NZD = 22,
+    CNY = 23,
+    INR = 24,
+    CLP = 25,
+    PEN = 26,
+    COP = 27,
+    ZAR = 28,
+    HKD = 29,
+    TWD = 30,
+    SAR = 31,
+    AED = 32,
+    ARS = 34,
+    ILS = 35,
+    KZT = 37,
+    KWD = 38,
+    QAR = 39,
+    CRC = 40,
+    UYU = 41,
+}
+
+impl Currency {
+    /// Steam currency id
+    pub fn id(&self) -> u32 {
+        *self as u32
+    }
+}
+
+impl fmt::Display for Currency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.id())
+    }
+}
+
+/// Parses a locale formatted price like `"1,23€"`, `"$1,234.56"` or
+/// `"12 345,67 pуб."` into hundredths of the currency unit
+///
+/// A separator followed by one or two digits at the end is treated as the
+/// decimal separator, all other separators as thousands separators. The
+/// currency isn't known here, so three decimal prices like `"1.234 KD"` are
+/// read as `123400` as well. Returns `None` if the price doesn't fit a `u64`.
+pub fn parse_price(text: &str) -> Option<u64> {
+    let start = text.find(|c: char| c.is_ascii_digit())?;
+    let end = text.rfind(|c: char| c.is_ascii_digit())? + 1;
+    let number: String = text[start..end]
+        .chars()
+        .filter(|c| c.is_ascii_digit() || *c == ',' || *c == '.')
+        .collect();
+
+    let (whole, fraction) = match number.rfind([',', '.']) {
+        Some(index) if number.len() - index - 1 <= 2 => (&number[..index], &number[index + 1..]),
+        _ => (number.as_str(), ""),
+    };
+    let whole: u64 = whole
+        .chars()
+        .filter(char::is_ascii_digit)
+        .collect::<String>()
+        .parse()
+        .ok()?;
+    let fraction: u64 = match fraction.len() {
+        0 => 0,
+        1 => fraction.parse::<u64>().ok()? * 10,
+        _ => fraction.parse().ok()?,
+    };
+    whole.checked_mul(100)?.checked_add(fraction)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_locale_formats() {
+        assert_eq!(parse_price("1,23€"), Some(123));
+        assert_eq!(parse_price("$0.45"), Some(45));
+        assert_eq!(parse_price("$1,234.56"), Some(123456));
+        assert_eq!(parse_price("1.234,5€"), Some(123450));
+        assert_eq!(parse_price("12 345,67 pуб."), Some(1234567));
+        assert_eq!(parse_price("¥ 1,234"), Some(123400));
+        assert_eq!(parse_price("CDN$ 3"), Some(300));
+        assert_eq!(parse_price("--"), None);
+        assert_eq!(parse_price("$1000000000000000000.00"), None);
+    }
+}
@@ -0,0 +1,130 @@
+use crate::error::Error;
+use crate::market::Currency;
+use crate::params::Params;
+use crate::utils::{check_status, Result, COMMUNITY_AUTHORITY};
+use crate::SteamClient;
+
+use hyper::body::to_bytes;
+use hyper::header::COOKIE;
+use hyper::{Body, Request, Uri};
+
+use serde::Deserialize;
+use serde_json::from_slice;
+
+const PATH: &str = "/market/pricehistory/";
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct PricePoint {
+    /// Unix timestamp of the start of the hour or day
+    pub date: u32,
+    /// Median sale price in hundredths of the currency
+    pub price: u64,
+    pub volume: u64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct PriceHistory {
+    pub currency: Currency,
+    /// Oldest first, daily points for old sales and hourly for recent ones
+    pub prices: Vec<PricePoint>,
+}
+
+#[derive(Deserialize)]
+struct Response {
+    success: bool,
+    #[serde(default)]
+    prices: Vec<(String, f64, String)>,
+}
+
+/// Parses dates like `"Nov 27 2013 01: +0"` to unix timestamps
+fn parse_date(date: &str) -> Option<u32> {
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+    let mut parts = date.split_whitespace();
+    let month_name = parts.next()?;
+    let month = MONTHS.iter().position(|m| *m == month_name)? as i64 + 1;
+    let day: i64 = parts.next()?.parse().ok()?;
+    let year: i64 = parts.next()?.parse().ok()?;
+    let hour: i64 = parts.next()?.trim_end_matches(':').parse().ok()?;
+
+    // Days since epoch from the civil date (Howard Hinnant's algorithm)
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let year_of_era = y - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146097 + day_of_era - 719468;
+
+    u32::try_from(days * 86400 + hour * 3600).ok()
+}
+
+impl SteamClient {
+    /// Gets the [PriceHistory] of a market item
+    ///
+    /// Steam only returns the history for logged in users, so `login_cookie`
+    /// must be the value of the `steamLoginSecure` cookie of a community
+    /// session.
+    pub async fn get_price_history(
+        &self,
+        app_id: u32,
+        market_hash_name: &str,
+        currency: Currency,
+        login_cookie: &str,
+    ) -> Result<PriceHistory> {
+        let query = Params::new()
+            .add("appid", app_id)
+            .add("market_hash_name", market_hash_name)
+            .add("currency", currency)
+            .encode();
+        let uri = Uri::builder()
+            .scheme("https")
+            .authority(COMMUNITY_AUTHORITY)
+            .path_and_query(format!("{}?{}", PATH, query))
+            .build()?;
+        let request = Request::get(uri)
+            .header(COOKIE, format!("steamLoginSecure={}", login_cookie))
+            .body(Body::empty())?;
+
+        let response = self.client.request(request).await?;
+        check_status(response.status())?;
+        let parsed = from_slice::<Response>(&to_bytes(response.into_body()).await?)?;
+
+        if !parsed.success {
+            return Err(Error::client("no market item with provided name"));
+        }
+        let prices = parsed
+            .prices
+            .into_iter()
+            .map(|(date, price, volume)| {
+                Some(PricePoint {
+                    date: parse_date(&date)?,
+                    price: (price * 100.0).round() as u64,
+                    volume: volume.parse().ok()?,
+                })
+            })
+            .collect::<Option<Vec<PricePoint>>>()
+            .ok_or_else(|| Error::client("unable to parse price history"))?;
+
+        Ok(PriceHistory { currency, prices })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_history_dates() {
+        assert_eq!(parse_date("Nov 27 2013 01: +0"), Some(1385514000));
+        assert_eq!(parse_date("Feb 29 2024 13: +0"), Some(1709211600));
+        assert_eq!(parse_date("Foo 29 2024 13: +0"), None);
+
+        let json = br#"{"success":true,"price_prefix":"","price_suffix":"\u20ac",
+            "prices":[["Nov 27 2013 01: +0",2.415,"12"]]}"#;
+        let response = from_slice::<Response>(json).unwrap();
+        assert_eq!(response.prices[0].1, 2.415);
+    }
+}
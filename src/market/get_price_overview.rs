@@ -0,0 +1,125 @@
+use crate::error::Error;
+use crate::market::{parse_price, Currency};
+use crate::params::Params;
+use crate::utils::{check_status, Result, COMMUNITY_AUTHORITY};
+use crate::SteamClient;
+
+use hyper::body::to_bytes;
+use hyper::Uri;
+
+use serde::Deserialize;
+use serde_json::from_slice;
+
+const PATH: &str = "/market/priceoverview/";
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct PriceOverview {
+    pub currency: Currency,
+    /// Lowest current listing in hundredths of the currency
+    pub lowest_price: Option<u64>,
+    /// Median sale price of the last 24 hours in hundredths of the currency
+    pub median_price: Option<u64>,
+    /// Number of sales in the last 24 hours
+    pub volume: Option<u64>,
+}
+
+#[derive(Deserialize)]
+struct Response {
+    success: bool,
+    lowest_price: Option<String>,
+    median_price: Option<String>,
+    volume: Option<String>,
+}
+
+impl Response {
+    fn into_overview(self, currency: Currency) -> Result<PriceOverview> {
+        if !self.success {
+            return Err(Error::client("no market item with provided name"));
+        }
+        Ok(PriceOverview {
+            currency,
+            lowest_price: self.lowest_price.as_deref().and_then(parse_price),
+            median_price: self.median_price.as_deref().and_then(parse_price),
+            // Large volumes have thousands separators, for example "1,234"
+            volume: self
+                .volume
+                .map(|v| v.chars().filter(char::is_ascii_digit).collect::<String>())
+                .and_then(|v| v.parse().ok()),
+        })
+    }
+}
+
+impl SteamClient {
+    /// Gets the [PriceOverview] of a market item
+    ///
+    /// Works without an API key. `country` is a two letter country code
+    /// which affects the taxes included in the prices.
+    pub async fn get_price_overview(
+        &self,
+        app_id: u32,
+        market_hash_name: &str,
+        currency: Currency,
+        country: Option<&str>,
+    ) -> Result<PriceOverview> {
+        let query = Params::new()
+            .add("appid", app_id)
+            .add("market_hash_name", market_hash_name)
+            .add("currency", currency)
+            .add_optional("country", country)
+            .encode();
+        let uri = Uri::builder()
+            .scheme("https")
+            .authority(COMMUNITY_AUTHORITY)
+            .path_and_query(format!("{}?{}", PATH, query))
+            .build()?;
+
+        let response = self.client.get(uri).await?;
+        check_status(response.status())?;
+        let parsed = from_slice::<Response>(&to_bytes(response.into_body()).await?)?;
+
+        parsed.into_overview(currency)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio_test::block_on;
+
+    #[test]
+    fn parse_overview() {
+        let json = br#"{"success":true,"lowest_price":"2,05\u20ac","volume":"1,234","median_price":"1,98\u20ac"}"#;
+        let overview = from_slice::<Response>(json)
+            .unwrap()
+            .into_overview(Currency::EUR)
+            .unwrap();
+        assert_eq!(overview.lowest_price, Some(205));
+        assert_eq!(overview.median_price, Some(198));
+        assert_eq!(overview.volume, Some(1234));
+
+        let json = br#"{"success":true,"lowest_price":"$1,024.50"}"#;
+        let overview = from_slice::<Response>(json)
+            .unwrap()
+            .into_overview(Currency::USD)
+            .unwrap();
+        assert_eq!(overview.lowest_price, Some(102450));
+        assert_eq!(overview.median_price, None);
+        assert_eq!(overview.volume, None);
+
+        let json = br#"{"success":false}"#;
+        assert!(from_slice::<Response>(json)
+            .unwrap()
+            .into_overview(Currency::USD)
+            .is_err());
+    }
+
+    #[test]
+    fn mann_co_key() {
+        let client = SteamClient::new();
+        let overview =
+            block_on(client.get_price_overview(440, "Mann Co. Supply Crate Key", Currency::EUR, None))
+                .unwrap();
+        assert!(overview.lowest_price.is_some());
+    }
+}
@@ -0,0 +1,14 @@
+//! Steam Community Market
+//!
+//! Prices are returned in hundredths of the currency unit, the same way
+//! Steam represents prices internally, so `"1,23€"` is `123`.
+
+mod currency;
+mod get_price_history;
+mod get_price_overview;
+mod search_listings;
+
+pub use currency::{parse_price, Currency};
+pub use get_price_history::{PriceHistory, PricePoint};
+pub use get_price_overview::PriceOverview;
+pub use search_listings::{ListingAsset, MarketListing, MarketSearch};
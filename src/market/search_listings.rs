@@ -0,0 +1,127 @@
+use crate::error::Error;
+use crate::params::Params;
+use crate::utils::{
+    bool_from_int, check_status, u64_from_str, Result, COMMUNITY_AUTHORITY,
+};
+use crate::SteamClient;
+
+use hyper::body::to_bytes;
+use hyper::Uri;
+
+use serde::Deserialize;
+use serde_json::from_slice;
+
+const PATH: &str = "/market/search/render/";
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct ListingAsset {
+    #[serde(rename = "appid")]
+    pub app_id: u32,
+    #[serde(rename = "classid")]
+    #[serde(deserialize_with = "u64_from_str")]
+    pub class_id: u64,
+    #[serde(rename = "instanceid")]
+    #[serde(deserialize_with = "u64_from_str")]
+    pub instance_id: u64,
+    pub name: String,
+    pub market_hash_name: String,
+    #[serde(rename = "type")]
+    #[serde(default)]
+    pub item_type: String,
+    #[serde(default)]
+    pub icon_url: String,
+    #[serde(deserialize_with = "bool_from_int")]
+    pub tradable: bool,
+    #[serde(deserialize_with = "bool_from_int")]
+    pub commodity: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct MarketListing {
+    pub name: String,
+    pub hash_name: String,
+    pub sell_listings: u32,
+    /// Lowest listing in US cents
+    pub sell_price: u64,
+    pub sell_price_text: String,
+    pub app_name: String,
+    pub asset_description: ListingAsset,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct MarketSearch {
+    pub start: u32,
+    pub total_count: u32,
+    pub results: Vec<MarketListing>,
+}
+
+#[derive(Deserialize)]
+struct Response {
+    success: bool,
+    #[serde(flatten)]
+    search: Option<MarketSearch>,
+}
+
+impl SteamClient {
+    /// Searches the market listings, optionally limited to one app
+    ///
+    /// Works without an API key. Returns at most `count` (max 100) listings
+    /// starting from `start`.
+    pub async fn search_market(
+        &self,
+        query: &str,
+        app_id: Option<u32>,
+        start: u32,
+        count: u32,
+    ) -> Result<MarketSearch> {
+        let query = Params::new()
+            .add("norender", 1)
+            .add("query", query)
+            .add("start", start)
+            .add("count", count)
+            .add_optional("appid", app_id)
+            .encode();
+        let uri = Uri::builder()
+            .scheme("https")
+            .authority(COMMUNITY_AUTHORITY)
+            .path_and_query(format!("{}?{}", PATH, query))
+            .build()?;
+
+        let response = self.client.get(uri).await?;
+        check_status(response.status())?;
+        let parsed = from_slice::<Response>(&to_bytes(response.into_body()).await?)?;
+
+        match parsed.search {
+            Some(search) if parsed.success => Ok(search),
+            _ => Err(Error::client("market search failed")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_search() {
+        let json = br#"{"success":true,"start":0,"pagesize":1,"total_count":2451,
+            "searchdata":{"query":"redline","search_descriptions":false,"total_count":2451,"pagesize":1},
+            "results":[{"name":"AK-47 | Redline (Field-Tested)","hash_name":"AK-47 | Redline (Field-Tested)",
+                "sell_listings":1024,"sell_price":4123,"sell_price_text":"$41.23","app_icon":"a.jpg",
+                "app_name":"Counter-Strike 2","asset_description":{"appid":730,"classid":"310776668",
+                "instanceid":"480085569","background_color":"","icon_url":"abc","tradable":1,
+                "name":"AK-47 | Redline (Field-Tested)","name_color":"D2D2D2","type":"Classified Rifle",
+                "market_name":"AK-47 | Redline (Field-Tested)","market_hash_name":"AK-47 | Redline (Field-Tested)",
+                "commodity":0},"sale_price_text":"$39.90"}]}"#;
+        let search = from_slice::<Response>(json).unwrap().search.unwrap();
+        assert_eq!(search.total_count, 2451);
+        assert_eq!(search.results[0].sell_price, 4123);
+        assert_eq!(search.results[0].asset_description.class_id, 310776668);
+
+        let failed = from_slice::<Response>(br#"{"success":false}"#).unwrap();
+        assert!(failed.search.is_none());
+    }
+}
@@ -0,0 +1,155 @@
+//! Parameters of API requests
+
+use core::fmt::Display;
+
+use crate::utils::encode;
+
+use serde::Serialize;
+
+/// Ordered list of request parameters
+///
+/// Used as the query string of GET requests and as a form-encoded body with
+/// [post](crate::SteamClient::post). Names and values are percent-encoded
+/// when the params are encoded, so user input can be passed as is.
+///
+/// ```
+/// use rsteam::Params;
+///
+/// let params = Params::new()
+///     .add("itemcount", 2)
+///     .add_list("publishedfileids", [104603291, 160250458]);
+/// assert_eq!(
+///     params.encode(),
+///     "itemcount=2&publishedfileids%5B0%5D=104603291&publishedfileids%5B1%5D=160250458"
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params {
+    pairs: Vec<(String, String)>,
+}
+
+impl Params {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a single parameter
+    pub fn add<T: Display>(mut self, name: &str, value: T) -> Self {
+        self.pairs.push((name.to_owned(), value.to_string()));
+        self
+    }
+
+    /// Adds the parameter if the value is `Some`
+    pub fn add_optional<T: Display>(self, name: &str, value: Option<T>) -> Self {
+        match value {
+            Some(value) => self.add(name, value),
+            None => self,
+        }
+    }
+
+    /// Adds the values as an indexed array: `name[0]`, `name[1]`, ...
+    pub fn add_list<T, I>(mut self, name: &str, values: I) -> Self
+    where
+        T: Display,
+        I: IntoIterator<Item = T>,
+    {
+        for (i, value) in values.into_iter().enumerate() {
+            self.pairs.push((format!("{}[{}]", name, i), value.to_string()));
+        }
+        self
+    }
+
+    /// Adds the values as a single comma separated parameter
+    pub fn add_joined<T, I>(self, name: &str, values: I) -> Self
+    where
+        T: Display,
+        I: IntoIterator<Item = T>,
+    {
+        let joined = values
+            .into_iter()
+            .map(|value| value.to_string())
+            .collect::<Vec<String>>()
+            .join(",");
+        if joined.is_empty() {
+            self
+        } else {
+            self.add(name, joined)
+        }
+    }
+
+    /// Adds the `input_json` parameter used by the service interfaces
+    /// (`IPlayerService`, `IEconService`, ...) instead of separate parameters
+    pub fn add_input_json<T: Serialize>(self, value: &T) -> serde_json::Result<Self> {
+        let json = serde_json::to_string(value)?;
+        Ok(self.add("input_json", json))
+    }
+
+    /// Whether a parameter with the name has been added
+    pub fn contains(&self, name: &str) -> bool {
+        self.pairs.iter().any(|(added, _)| added == name)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pairs.is_empty()
+    }
+
+    /// Percent-encodes the params to `name=value` pairs joined with `&`
+    pub fn encode(&self) -> String {
+        self.pairs
+            .iter()
+            .map(|(name, value)| format!("{}={}", encode(name), encode(value)))
+            .collect::<Vec<String>>()
+            .join("&")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encoding() {
+        let params = Params::new()
+            .add("search_text", "dark rp & more")
+            .add_optional("language", None::<&str>)
+            .add_optional("cursor", Some("AoJw+/=="))
+            .add_list("tags", ["Map", "ä"]);
+        assert_eq!(
+            params.encode(),
+            "search_text=dark%20rp%20%26%20more&cursor=AoJw%2B%2F%3D%3D\
+             &tags%5B0%5D=Map&tags%5B1%5D=%C3%A4"
+        );
+        assert!(Params::new().encode().is_empty());
+    }
+
+    #[test]
+    fn joined_values() {
+        let params = Params::new()
+            .add_joined("steamids", [76561198061271782u64, 76561197960435530])
+            .add_joined("feeds", Vec::<String>::new())
+            .add_joined("tags", ["patch&notes", "news"]);
+        assert_eq!(
+            params.encode(),
+            "steamids=76561198061271782%2C76561197960435530&tags=patch%26notes%2Cnews"
+        );
+    }
+
+    #[test]
+    fn input_json() {
+        #[derive(Serialize)]
+        struct Input {
+            steamid: u64,
+            appids_filter: Vec<u32>,
+        }
+
+        let input = Input {
+            steamid: 76561198061271782,
+            appids_filter: vec![440, 570],
+        };
+        let params = Params::new().add("key", "ABC").add_input_json(&input).unwrap();
+        assert_eq!(
+            params.encode(),
+            "key=ABC&input_json=%7B%22steamid%22%3A76561198061271782%2C%22appids_filter%22%3A%5B440%2C570%5D%7D"
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use crate::error::Error;
-use crate::utils::{ResponseWrapper, Result, AUTHORITY};
+use crate::params::Params;
+use crate::utils::{check_ids, ResponseWrapper, Result, AUTHORITY};
 use crate::{SteamClient, SteamID};
 use hyper::body::to_bytes;
 use hyper::Uri;
@@ -8,10 +9,12 @@ use serde_json::from_slice;
 
 const PATH: &str = "/IPlayerService/GetBadges/v0001/";
 
-#[derive(Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
 pub struct Badge {
     pub badgeid: u32,
     pub level: u32,
+    /// Unix timestamp of when the badge was completed
     pub completion_time: u32,
     pub communityitemid: Option<String>,
     pub border_color: Option<u32>,
@@ -20,7 +23,16 @@ pub struct Badge {
     pub scarcity: u32,
 }
 
-#[derive(Deserialize)]
+#[cfg(feature = "time")]
+impl Badge {
+    /// [completion_time](Badge::completion_time) as [OffsetDateTime](time::OffsetDateTime)
+    pub fn completion_datetime(&self) -> time::OffsetDateTime {
+        crate::timestamp::from_unix(self.completion_time)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
 pub struct Badges {
     pub badges: Vec<Badge>,
     pub player_xp: u32,
@@ -38,8 +50,12 @@ impl SteamClient {
             .api_key
             .as_ref()
             .ok_or_else(|| Error::client("API key required"))?;
+        check_ids(&[*id])?;
 
-        let query = format!("key={}&steamid={}", api_key, id);
+        let query = Params::new()
+            .add("key", api_key)
+            .add("steamid", id)
+            .encode();
 
         let uri = Uri::builder()
             .scheme("https")
@@ -1,5 +1,6 @@
 use crate::error::Error;
-use crate::utils::{ResponseWrapper, Result, AUTHORITY};
+use crate::params::Params;
+use crate::utils::{check_ids, ResponseWrapper, Result, AUTHORITY};
 use crate::{SteamClient, SteamID};
 use hyper::body::to_bytes;
 use hyper::Uri;
@@ -8,7 +9,8 @@ use serde_json::from_slice;
 
 const PATH: &str = "/IPlayerService/GetCommunityBadgeProgress/v0001/";
 
-#[derive(Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
 pub struct Quest {
     pub questid: u32,
     pub completed: bool,
@@ -28,8 +30,12 @@ impl SteamClient {
             .api_key
             .as_ref()
             .ok_or_else(|| Error::client("API key required"))?;
+        check_ids(&[*id])?;
 
-        let query = format!("key={}&steamid={}", api_key, id);
+        let query = Params::new()
+            .add("key", api_key)
+            .add("steamid", id)
+            .encode();
 
         let uri = Uri::builder()
             .scheme("https")
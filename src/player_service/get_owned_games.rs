@@ -1,5 +1,8 @@
+use std::time::Duration;
+
 use crate::error::Error;
-use crate::utils::{ResponseWrapper, Result, AUTHORITY};
+use crate::params::Params;
+use crate::utils::{check_ids, ResponseWrapper, Result, AUTHORITY};
 use crate::{SteamClient, SteamID};
 use serde::Deserialize;
 use serde_json::from_slice;
@@ -11,7 +14,8 @@ const PATH: &str = "/IPlayerService/GetOwnedGames/v0001/";
 
 /// The playtimes are in minutes and platform specific playtimes
 /// may not add up to playtime_forever.
-#[derive(Deserialize, Debug)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
 pub struct Game {
     pub appid: u32,
     pub name: Option<String>,
@@ -23,7 +27,15 @@ pub struct Game {
     pub playtime_linux_forever: u32,
 }
 
-#[derive(Deserialize, Debug)]
+impl Game {
+    /// [playtime_forever](Game::playtime_forever) as [Duration]
+    pub fn playtime_forever_duration(&self) -> Duration {
+        Duration::from_secs(self.playtime_forever as u64 * 60)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
 pub struct OwnedGames {
     pub game_count: u32,
     pub games: Vec<Game>,
@@ -49,13 +61,16 @@ impl SteamClient {
             .api_key
             .as_ref()
             .ok_or_else(|| Error::client("API key required"))?;
+        check_ids(&[*id])?;
 
-        let q1 = optional_query!(include_app_info);
-        let q2 = optional_query!(include_played_free_games);
-        let q3 = optional_query!(include_free_sub);
-        let q4 = optional_query!(skip_unvetted_apps);
-
-        let query = format!("key={}&steamid={}{}{}{}{}", api_key, id, q1, q2, q3, q4);
+        let query = Params::new()
+            .add("key", api_key)
+            .add("steamid", id)
+            .add_optional("include_app_info", include_app_info)
+            .add_optional("include_played_free_games", include_played_free_games)
+            .add_optional("include_free_sub", include_free_sub)
+            .add_optional("skip_unvetted_apps", skip_unvetted_apps)
+            .encode();
         let uri = Uri::builder()
             .scheme("https")
             .authority(AUTHORITY)
@@ -1,5 +1,8 @@
+use std::time::Duration;
+
 use crate::error::Error;
-use crate::utils::{ResponseMaybeEmpty, Result, AUTHORITY};
+use crate::params::Params;
+use crate::utils::{check_ids, ResponseMaybeEmpty, Result, AUTHORITY};
 use crate::{SteamClient, SteamID};
 use serde::Deserialize;
 use serde_json::from_slice;
@@ -11,7 +14,8 @@ const PATH: &str = "/IPlayerService/GetRecentlyPlayedGames/v0001/";
 
 /// The playtimes are in minutes and platform specific playtimes
 /// may not add up to playtime_forever.
-#[derive(Deserialize, Debug)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
 pub struct Game {
     pub appid: u32,
     pub name: String,
@@ -23,7 +27,20 @@ pub struct Game {
     pub playtime_linux_forever: u32,
 }
 
-#[derive(Deserialize, Debug)]
+impl Game {
+    /// [playtime_2weeks](Game::playtime_2weeks) as [Duration]
+    pub fn playtime_2weeks_duration(&self) -> Duration {
+        Duration::from_secs(self.playtime_2weeks as u64 * 60)
+    }
+
+    /// [playtime_forever](Game::playtime_forever) as [Duration]
+    pub fn playtime_forever_duration(&self) -> Duration {
+        Duration::from_secs(self.playtime_forever as u64 * 60)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
 pub struct RecentlyPlayedGames {
     pub total_count: u32,
     pub games: Vec<Game>,
@@ -45,10 +62,13 @@ impl SteamClient {
             .api_key
             .as_ref()
             .ok_or_else(|| Error::client("API key required"))?;
+        check_ids(&[*id])?;
 
-        let count_query = optional_query!(count);
-
-        let query = format!("key={}&steamid={}{}", api_key, id, count_query);
+        let query = Params::new()
+            .add("key", api_key)
+            .add("steamid", id)
+            .add_optional("count", count)
+            .encode();
         let uri = Uri::builder()
             .scheme("https")
             .authority(AUTHORITY)
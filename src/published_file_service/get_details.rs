@@ -0,0 +1,217 @@
+use crate::error::Error;
+use crate::steam_id::SteamID;
+use crate::steam_remote_storage::CollectionItem;
+use crate::params::Params;
+use crate::utils::{found_results, u64_from_str, Result, ResponseWrapper, AUTHORITY};
+use crate::SteamClient;
+
+use hyper::body::to_bytes;
+use hyper::Uri;
+
+use serde::Deserialize;
+use serde_aux::field_attributes::deserialize_number_from_string;
+use serde_json::{from_slice, Value};
+
+const PATH: &str = "/IPublishedFileService/GetDetails/v1/";
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct WorkshopTag {
+    pub tag: String,
+    /// Localized name of the tag
+    #[serde(default)]
+    pub display_name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct VoteData {
+    /// Between 0 and 1
+    pub score: f32,
+    pub votes_up: u32,
+    pub votes_down: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct WorkshopItem {
+    #[serde(rename = "publishedfileid")]
+    #[serde(deserialize_with = "u64_from_str")]
+    #[cfg_attr(feature = "serde-serialize", serde(serialize_with = "crate::utils::u64_to_str"))]
+    pub id: u64,
+    pub creator: SteamID,
+    /// App used to upload the item
+    #[serde(rename = "creator_appid")]
+    pub creator_app_id: u32,
+    /// App the item belongs to
+    #[serde(rename = "consumer_appid")]
+    pub consumer_app_id: u32,
+    pub title: String,
+    #[serde(rename = "file_description")]
+    #[serde(default)]
+    pub description: String,
+    /// Only returned when requested
+    pub short_description: Option<String>,
+    /// `0` for items, `2` for collections
+    #[serde(default)]
+    pub file_type: u32,
+    /// Size in bytes
+    #[serde(default, deserialize_with = "deserialize_number_from_string")]
+    pub file_size: u64,
+    #[serde(default)]
+    pub file_url: String,
+    #[serde(default)]
+    pub preview_url: String,
+    #[serde(default)]
+    pub visibility: u32,
+    #[serde(default)]
+    pub banned: bool,
+    #[serde(default)]
+    pub subscriptions: u32,
+    #[serde(default)]
+    pub favorited: u32,
+    #[serde(default)]
+    pub followers: u32,
+    #[serde(default)]
+    pub lifetime_subscriptions: u32,
+    #[serde(default)]
+    pub lifetime_favorited: u32,
+    #[serde(default)]
+    pub views: u32,
+    #[serde(default)]
+    pub num_children: u32,
+    #[serde(default)]
+    pub tags: Vec<WorkshopTag>,
+    /// Only returned for collections when requested
+    #[serde(default)]
+    pub children: Vec<CollectionItem>,
+    /// Only returned when requested
+    pub vote_data: Option<VoteData>,
+    /// Unix timestamp of the upload
+    pub time_created: u32,
+    /// Unix timestamp of the last update
+    pub time_updated: u32,
+}
+
+#[cfg(feature = "time")]
+impl WorkshopItem {
+    /// [time_created](WorkshopItem::time_created) as [OffsetDateTime](time::OffsetDateTime)
+    pub fn time_created_datetime(&self) -> time::OffsetDateTime {
+        crate::timestamp::from_unix(self.time_created)
+    }
+
+    /// [time_updated](WorkshopItem::time_updated) as [OffsetDateTime](time::OffsetDateTime)
+    pub fn time_updated_datetime(&self) -> time::OffsetDateTime {
+        crate::timestamp::from_unix(self.time_updated)
+    }
+}
+
+#[derive(Deserialize)]
+struct Details {
+    #[serde(rename = "publishedfiledetails")]
+    #[serde(default)]
+    items: Vec<Value>,
+}
+
+type Response = ResponseWrapper<Details>;
+
+impl SteamClient {
+    /// Gets the [WorkshopItems](WorkshopItem) for the given ids
+    ///
+    /// Requires an API key. Tags, children of collections and vote data are
+    /// always included, `language` (for example `"english"`) localizes the
+    /// tag names. Items that don't exist or aren't visible are left out.
+    pub async fn get_file_details(
+        &self,
+        ids: &[u64],
+        language: Option<&str>,
+    ) -> Result<Vec<WorkshopItem>> {
+        let api_key = self
+            .api_key
+            .as_ref()
+            .ok_or_else(|| Error::client("API key required"))?;
+
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let query = Params::new()
+            .add("key", api_key)
+            .add("includetags", true)
+            .add("includechildren", true)
+            .add("includevotes", true)
+            .add_list("publishedfileids", ids)
+            .add_optional("language", language)
+            .encode();
+        let uri = Uri::builder()
+            .scheme("https")
+            .authority(AUTHORITY)
+            .path_and_query(format!("{}?{}", PATH, query))
+            .build()?;
+
+        let response = self.client.get(uri).await?;
+        let body = response.into_body();
+        let parsed = from_slice::<Response>(&to_bytes(body).await?)?;
+
+        found_results(parsed.response.items)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use tokio_test::block_on;
+
+    #[test]
+    fn parse_details() {
+        let json = br#"{"response":{"publishedfiledetails":[
+            {"result":1,"publishedfileid":"532500598","creator":"76561198011965365","creator_appid":766,
+                "consumer_appid":4000,"consumer_shortcutid":0,"filename":"","file_size":"0",
+                "preview_file_size":"53012","file_url":"","preview_url":"https://steamuserimages-a.akamaihd.net/ugc/1/",
+                "url":"","hcontent_preview":"1","title":"Collection","file_description":"Addons",
+                "time_created":1443387210,"time_updated":1443387299,"visibility":0,"flags":0,
+                "workshop_file":false,"workshop_accepted":false,"show_subscribe_all":true,
+                "num_comments_public":3,"banned":false,"ban_reason":"","banner":"76561197960265728",
+                "can_be_deleted":true,"app_name":"Garry's Mod","file_type":2,"can_subscribe":true,
+                "subscriptions":0,"favorited":120,"followers":0,"lifetime_subscriptions":0,
+                "lifetime_favorited":130,"lifetime_followers":0,"lifetime_playtime":"0",
+                "lifetime_playtime_sessions":"0","views":9000,"num_children":2,"num_reports":0,
+                "tags":[{"tag":"Collection","display_name":"Collection"}],
+                "children":[{"publishedfileid":"1234","sortorder":1,"file_type":0}],
+                "vote_data":{"score":0.87,"votes_up":50,"votes_down":5},"language":0,
+                "maybe_inappropriate_sex":false,"maybe_inappropriate_violence":false,
+                "revision_change_number":"0","revision":1,"ban_text_check_result":0},
+            {"publishedfileid":"1","result":9}]}}"#;
+        let items =
+            found_results::<WorkshopItem>(from_slice::<Response>(json).unwrap().response.items)
+                .unwrap();
+        assert_eq!(items.len(), 1);
+        let item = &items[0];
+        assert_eq!(item.id, 532500598);
+        assert_eq!(item.file_type, 2);
+        assert!(!item.banned);
+        assert_eq!(item.children[0].id, 1234);
+        assert_eq!(item.tags[0].display_name, "Collection");
+        assert_eq!(item.vote_data.as_ref().map(|votes| votes.votes_up), Some(50));
+    }
+
+    #[test]
+    fn malformed_item() {
+        let json = br#"{"response":{"publishedfiledetails":[
+            {"result":1,"publishedfileid":"532500598","creator":"76561198011965365","title":"Collection"},
+            {"publishedfileid":"1","result":9}]}}"#;
+        let items = from_slice::<Response>(json).unwrap().response.items;
+        assert!(matches!(
+            found_results::<WorkshopItem>(items),
+            Err(Error::JsonParser(_))
+        ));
+    }
+
+    #[test]
+    fn missing_item() {
+        let client = SteamClient::with_api_key(&env::var("STEAM_API_KEY").unwrap());
+        let items = block_on(client.get_file_details(&[1], None)).unwrap();
+        assert!(items.is_empty());
+    }
+}
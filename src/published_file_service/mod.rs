@@ -0,0 +1,7 @@
+//! Implementations for the IPublishedFileService interface
+
+mod get_details;
+mod query_files;
+
+pub use get_details::{VoteData, WorkshopItem, WorkshopTag};
+pub use query_files::{QueryFiles, QueryFilesPage, QueryType};
@@ -0,0 +1,336 @@
+use crate::error::Error;
+use crate::params::Params;
+use crate::published_file_service::WorkshopItem;
+use crate::utils::{found_results, Result, ResponseWrapper, AUTHORITY};
+use crate::SteamClient;
+
+use futures::stream::{self, Stream, TryStreamExt};
+use hyper::body::to_bytes;
+use hyper::Uri;
+
+use serde::Deserialize;
+use serde_json::{from_slice, Value};
+
+const PATH: &str = "/IPublishedFileService/QueryFiles/v1/";
+
+/// Order of the [QueryFiles] results
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum QueryType {
+    #[default]
+    RankedByVote = 0,
+    RankedByPublicationDate = 1,
+    AcceptedForGameRankedByAcceptanceDate = 2,
+    /// Uses [days](QueryFiles::days) as the trending period
+    RankedByTrend = 3,
+    FavoritedByFriendsRankedByPublicationDate = 4,
+    CreatedByFriendsRankedByPublicationDate = 5,
+    RankedByNumTimesReported = 6,
+    CreatedByFollowedUsersRankedByPublicationDate = 7,
+    NotYetRated = 8,
+    RankedByTotalUniqueSubscriptions = 9,
+    RankedByTotalVotesAsc = 10,
+    RankedByVotesUp = 11,
+    RankedByTextSearch = 12,
+    RankedByPlaytimeTrend = 13,
+    RankedByTotalPlaytime = 14,
+    RankedByAveragePlaytimeTrend = 15,
+    RankedByLifetimeAveragePlaytime = 16,
+    RankedByPlaytimeSessionsTrend = 17,
+    RankedByLifetimePlaytimeSessions = 18,
+    RankedByLastUpdatedDate = 21,
+}
+
+/// Builder for workshop queries
+///
+/// Used with [query_files](SteamClient::query_files) and
+/// [query_files_stream](SteamClient::query_files_stream).
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueryFiles {
+    app_id: u32,
+    query_type: QueryType,
+    search_text: Option<String>,
+    required_tags: Vec<String>,
+    excluded_tags: Vec<String>,
+    match_all_tags: bool,
+    file_type: u32,
+    days: Option<u32>,
+    per_page: u32,
+    return_tags: bool,
+    return_children: bool,
+    return_vote_data: bool,
+    return_short_description: bool,
+    return_previews: bool,
+    return_metadata: bool,
+}
+
+impl QueryFiles {
+    /// Query for the items of `app_id`, ranked by votes
+    pub fn new(app_id: u32) -> Self {
+        QueryFiles {
+            app_id,
+            query_type: QueryType::default(),
+            search_text: None,
+            required_tags: Vec::new(),
+            excluded_tags: Vec::new(),
+            match_all_tags: true,
+            file_type: 0,
+            days: None,
+            per_page: 100,
+            return_tags: false,
+            return_children: false,
+            return_vote_data: false,
+            return_short_description: false,
+            return_previews: false,
+            return_metadata: false,
+        }
+    }
+
+    pub fn query_type(mut self, query_type: QueryType) -> Self {
+        self.query_type = query_type;
+        self
+    }
+
+    /// Only return items matching the text
+    pub fn search_text(mut self, text: &str) -> Self {
+        self.search_text = Some(text.to_owned());
+        self
+    }
+
+    /// Only return items with the tag, can be called multiple times
+    pub fn required_tag(mut self, tag: &str) -> Self {
+        self.required_tags.push(tag.to_owned());
+        self
+    }
+
+    /// Leave out items with the tag, can be called multiple times
+    pub fn excluded_tag(mut self, tag: &str) -> Self {
+        self.excluded_tags.push(tag.to_owned());
+        self
+    }
+
+    /// Whether items need all the required tags or just one of them, default true
+    pub fn match_all_tags(mut self, match_all: bool) -> Self {
+        self.match_all_tags = match_all;
+        self
+    }
+
+    /// `0` for items (default), `1` for collections
+    pub fn file_type(mut self, file_type: u32) -> Self {
+        self.file_type = file_type;
+        self
+    }
+
+    /// Trending period for [RankedByTrend](QueryType::RankedByTrend)
+    pub fn days(mut self, days: u32) -> Self {
+        self.days = Some(days);
+        self
+    }
+
+    /// Items per request, default and max 100
+    pub fn per_page(mut self, per_page: u32) -> Self {
+        self.per_page = per_page;
+        self
+    }
+
+    pub fn return_tags(mut self, value: bool) -> Self {
+        self.return_tags = value;
+        self
+    }
+
+    pub fn return_children(mut self, value: bool) -> Self {
+        self.return_children = value;
+        self
+    }
+
+    pub fn return_vote_data(mut self, value: bool) -> Self {
+        self.return_vote_data = value;
+        self
+    }
+
+    pub fn return_short_description(mut self, value: bool) -> Self {
+        self.return_short_description = value;
+        self
+    }
+
+    pub fn return_previews(mut self, value: bool) -> Self {
+        self.return_previews = value;
+        self
+    }
+
+    pub fn return_metadata(mut self, value: bool) -> Self {
+        self.return_metadata = value;
+        self
+    }
+
+    fn to_params(&self, api_key: &str, cursor: &str) -> Params {
+        let mut params = Params::new()
+            .add("key", api_key)
+            .add("cursor", cursor)
+            .add("query_type", self.query_type as u32)
+            .add("appid", self.app_id)
+            .add("filetype", self.file_type)
+            .add("numperpage", self.per_page)
+            .add("match_all_tags", self.match_all_tags)
+            .add_optional("search_text", self.search_text.as_ref())
+            .add_list("requiredtags", &self.required_tags)
+            .add_list("excludedtags", &self.excluded_tags)
+            .add_optional("days", self.days);
+        let flags = [
+            ("return_tags", self.return_tags),
+            ("return_children", self.return_children),
+            ("return_vote_data", self.return_vote_data),
+            ("return_short_description", self.return_short_description),
+            ("return_previews", self.return_previews),
+            ("return_metadata", self.return_metadata),
+        ];
+        for (name, value) in flags {
+            if value {
+                params = params.add(name, true);
+            }
+        }
+        params
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct QueryFilesPage {
+    /// Number of items matching the query
+    pub total: u32,
+    pub items: Vec<WorkshopItem>,
+    /// Cursor of the next page, `None` after the last page
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct Page {
+    #[serde(default)]
+    total: u32,
+    #[serde(rename = "publishedfiledetails")]
+    #[serde(default)]
+    items: Vec<Value>,
+    next_cursor: Option<String>,
+}
+
+type Response = ResponseWrapper<Page>;
+
+impl Page {
+    fn into_page(self, cursor: &str) -> Result<QueryFilesPage> {
+        // The last page returns its own cursor again
+        let next_cursor = match self.next_cursor {
+            Some(next) if !self.items.is_empty() && next != cursor => Some(next),
+            _ => None,
+        };
+        Ok(QueryFilesPage {
+            total: self.total,
+            items: found_results(self.items)?,
+            next_cursor,
+        })
+    }
+}
+
+impl SteamClient {
+    /// Gets a page of [WorkshopItems](WorkshopItem) matching the [QueryFiles]
+    ///
+    /// Requires an API key. Pass `"*"` as the cursor for the first page and
+    /// [next_cursor](QueryFilesPage::next_cursor) for the following ones, or use
+    /// [query_files_stream](SteamClient::query_files_stream).
+    pub async fn query_files(&self, query: &QueryFiles, cursor: &str) -> Result<QueryFilesPage> {
+        let api_key = self
+            .api_key
+            .as_ref()
+            .ok_or_else(|| Error::client("API key required"))?;
+
+        let query = query.to_params(api_key, cursor).encode();
+        let uri = Uri::builder()
+            .scheme("https")
+            .authority(AUTHORITY)
+            .path_and_query(format!("{}?{}", PATH, query))
+            .build()?;
+
+        let response = self.client.get(uri).await?;
+        let body = response.into_body();
+        let parsed = from_slice::<Response>(&to_bytes(body).await?)?;
+
+        parsed.response.into_page(cursor)
+    }
+
+    /// Returns a stream of all the [WorkshopItems](WorkshopItem) matching the [QueryFiles]
+    ///
+    /// Requires an API key. Pages are fetched as the stream is consumed,
+    /// following the cursors until the results are exhausted.
+    pub fn query_files_stream(&self, query: QueryFiles) -> impl Stream<Item = Result<WorkshopItem>> + '_ {
+        stream::try_unfold(
+            (query, Some("*".to_owned())),
+            move |(query, cursor)| async move {
+                let cursor = match cursor {
+                    Some(cursor) => cursor,
+                    None => return Ok(None),
+                };
+                let page = self.query_files(&query, &cursor).await?;
+                Ok::<_, Error>(Some((page.items, (query, page.next_cursor))))
+            },
+        )
+        .map_ok(|items| stream::iter(items.into_iter().map(Ok)))
+        .try_flatten()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+    use std::env;
+    use tokio_test::block_on;
+
+    #[test]
+    fn query_string() {
+        let query = QueryFiles::new(4000)
+            .query_type(QueryType::RankedByTrend)
+            .days(7)
+            .search_text("dark rp")
+            .required_tag("Map")
+            .required_tag("Roleplay & Fun")
+            .return_tags(true);
+        assert_eq!(
+            query.to_params("ABC", "AoJw+/==").encode(),
+            "key=ABC&cursor=AoJw%2B%2F%3D%3D&query_type=3&appid=4000&filetype=0&numperpage=100\
+             &match_all_tags=true&search_text=dark%20rp&requiredtags%5B0%5D=Map&requiredtags%5B1%5D=Roleplay%20%26%20Fun\
+             &days=7&return_tags=true"
+        );
+    }
+
+    #[test]
+    fn parse_pages() {
+        let json = br#"{"response":{"total":2,"publishedfiledetails":[
+            {"result":1,"publishedfileid":"104603291","creator":"76561198011965365","creator_appid":4000,
+                "consumer_appid":4000,"file_size":"1024","title":"Wiremod","time_created":1349375462,
+                "time_updated":1583341262}],
+            "next_cursor":"AoJwrY2b0PYCdbDjMA=="}}"#;
+        let page = from_slice::<Response>(json)
+            .unwrap()
+            .response
+            .into_page("*")
+            .unwrap();
+        assert_eq!(page.total, 2);
+        assert_eq!(page.items[0].id, 104603291);
+        assert_eq!(page.next_cursor.as_deref(), Some("AoJwrY2b0PYCdbDjMA=="));
+
+        let json = br#"{"response":{"total":2,"next_cursor":"AoJwrY2b0PYCdbDjMA=="}}"#;
+        let page = from_slice::<Response>(json)
+            .unwrap()
+            .response
+            .into_page("AoJwrY2b0PYCdbDjMA==")
+            .unwrap();
+        assert!(page.items.is_empty());
+        assert_eq!(page.next_cursor, None);
+    }
+
+    #[test]
+    fn gmod_trending() {
+        let client = SteamClient::with_api_key(&env::var("STEAM_API_KEY").unwrap());
+        let query = QueryFiles::new(4000).query_type(QueryType::RankedByTrend).per_page(10);
+        let items: Vec<_> = block_on(client.query_files_stream(query).take(15).collect());
+        assert_eq!(items.len(), 15);
+    }
+}
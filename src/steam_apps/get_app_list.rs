@@ -9,7 +9,8 @@ use serde_json::from_slice;
 
 const PATH: &str = "/ISteamApps/GetAppList/v0002";
 
-#[derive(Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
 pub struct App {
     #[serde(rename = "appid")]
     pub id: u32,
@@ -29,7 +30,9 @@ struct Response {
 impl SteamClient {
     /// Gets full list of all applications available in the steam store
     ///
-    /// App list is very long so it's not recommended to query often
+    /// App list is very long so it's not recommended to query often.
+    /// [get_store_app_list_stream](SteamClient::get_store_app_list_stream)
+    /// fetches the list in pages and can be limited to recently changed apps.
     pub async fn get_app_list(&self) -> Result<Vec<App>> {
         let uri = Uri::builder()
             .scheme("https")
@@ -0,0 +1,237 @@
+use std::collections::HashMap;
+use std::num::NonZeroU32;
+
+use crate::error::Error;
+use crate::params::Params;
+use crate::utils::{bool_from_str, u64_from_str, vec_from_indexed_map, Result, AUTHORITY};
+use crate::SteamClient;
+
+use hyper::body::to_bytes;
+use hyper::Uri;
+
+use serde::Deserialize;
+use serde_aux::field_attributes::deserialize_option_number_from_string;
+use serde_json::from_slice;
+
+const PATH: &str = "/ISteamEconomy/GetAssetClassInfo/v1/";
+const IMAGE_URL: &str = "https://community.cloudflare.steamstatic.com/economy/image/";
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct AssetDescription {
+    /// Usually `html` or `text`
+    #[serde(rename = "type")]
+    #[serde(default)]
+    pub description_type: String,
+    pub value: String,
+    pub color: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct AssetTag {
+    pub internal_name: String,
+    /// Localized name of the tag
+    pub name: String,
+    pub category: String,
+    /// Localized name of the category
+    pub category_name: String,
+    pub color: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct AssetClassInfo {
+    #[serde(rename = "classid")]
+    #[serde(deserialize_with = "u64_from_str")]
+    pub class_id: u64,
+    /// Only returned when the instance was requested
+    #[serde(rename = "instanceid")]
+    #[serde(default, deserialize_with = "deserialize_option_number_from_string")]
+    pub instance_id: Option<u64>,
+    pub name: String,
+    #[serde(default)]
+    pub market_name: String,
+    #[serde(default)]
+    pub market_hash_name: String,
+    #[serde(rename = "type")]
+    #[serde(default)]
+    pub item_type: String,
+    /// Path of the icon, see [icon_image_url](AssetClassInfo::icon_image_url)
+    #[serde(default)]
+    pub icon_url: String,
+    #[serde(default)]
+    pub icon_url_large: String,
+    #[serde(default)]
+    pub name_color: String,
+    #[serde(default)]
+    pub background_color: String,
+    #[serde(deserialize_with = "bool_from_str")]
+    pub tradable: bool,
+    #[serde(deserialize_with = "bool_from_str")]
+    pub marketable: bool,
+    #[serde(deserialize_with = "bool_from_str")]
+    pub commodity: bool,
+    #[serde(default, deserialize_with = "vec_from_indexed_map")]
+    pub descriptions: Vec<AssetDescription>,
+    #[serde(default, deserialize_with = "vec_from_indexed_map")]
+    pub tags: Vec<AssetTag>,
+}
+
+impl AssetClassInfo {
+    /// Full URL of the icon
+    pub fn icon_image_url(&self) -> String {
+        format!("{}{}", IMAGE_URL, self.icon_url)
+    }
+
+    /// Full URL of the large icon
+    pub fn icon_large_image_url(&self) -> String {
+        format!("{}{}", IMAGE_URL, self.icon_url_large)
+    }
+}
+
+#[derive(Deserialize)]
+struct ClassInfoResult {
+    success: bool,
+    error: Option<String>,
+    #[serde(flatten)]
+    classes: HashMap<String, AssetClassInfo>,
+}
+
+#[derive(Deserialize)]
+struct Response {
+    result: ClassInfoResult,
+}
+
+/// The classes are keyed by their ids in the response, so they come in hash order
+fn in_request_order(
+    infos: HashMap<String, AssetClassInfo>,
+    classes: &[(u64, Option<u64>)],
+) -> Vec<AssetClassInfo> {
+    let mut infos: Vec<AssetClassInfo> = infos.into_values().collect();
+    infos.sort_by_key(|info| {
+        classes
+            .iter()
+            .position(|&(class_id, instance_id)| {
+                class_id == info.class_id
+                    && (instance_id.is_none()
+                        || info.instance_id.is_none()
+                        || instance_id == info.instance_id)
+            })
+            .unwrap_or(usize::MAX)
+    });
+    infos
+}
+
+impl SteamClient {
+    /// Gets [AssetClassInfos](AssetClassInfo) for the given class ids
+    ///
+    /// Requires an API key. Classes are given as `(class_id, instance_id)` pairs,
+    /// all of them are fetched with a single request. Descriptions and tags are
+    /// localized to `language` (for example `"english"`) if provided. The
+    /// infos are in the same order as `classes`, unknown classes are left out.
+    pub async fn get_asset_class_info(
+        &self,
+        app_id: NonZeroU32,
+        classes: &[(u64, Option<u64>)],
+        language: Option<&str>,
+    ) -> Result<Vec<AssetClassInfo>> {
+        let api_key = self
+            .api_key
+            .as_ref()
+            .ok_or_else(|| Error::client("API key required"))?;
+
+        if classes.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut params = Params::new()
+            .add("key", api_key)
+            .add("appid", app_id)
+            .add("class_count", classes.len());
+        for (i, (class_id, instance_id)) in classes.iter().enumerate() {
+            params = params
+                .add(&format!("classid{}", i), class_id)
+                .add_optional(&format!("instanceid{}", i), instance_id.as_ref());
+        }
+        let query = params.add_optional("language", language).encode();
+        let uri = Uri::builder()
+            .scheme("https")
+            .authority(AUTHORITY)
+            .path_and_query(format!("{}?{}", PATH, query))
+            .build()?;
+
+        let response = self.client.get(uri).await?;
+        let body = response.into_body();
+        let parsed = from_slice::<Response>(&to_bytes(body).await?)?;
+
+        let ClassInfoResult {
+            success,
+            error,
+            classes: infos,
+        } = parsed.result;
+
+        if success {
+            Ok(in_request_order(infos, classes))
+        } else {
+            Err(Error::Client(format!(
+                "request failed: {}",
+                error.unwrap_or_else(|| "no message".to_owned())
+            )))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use tokio_test::block_on;
+
+    #[test]
+    fn parse_class_info() {
+        let json = br#"{"result":{
+            "5002":{"icon_url":"fWFc82js0fmoRAP","icon_url_large":"","name":"Refined Metal",
+                "market_hash_name":"Refined Metal","market_name":"Refined Metal","name_color":"7D6D00",
+                "background_color":"3C352E","type":"Level 3 Craft Item","tradable":"1","marketable":"1",
+                "commodity":"1","fraudwarnings":"","descriptions":"","owner_descriptions":"",
+                "tags":{"0":{"internal_name":"Unique","name":"Unique","category":"Quality","color":"7D6D00","category_name":"Quality"},
+                    "1":{"internal_name":"Craft Item","name":"Craft Item","category":"Type","category_name":"Type"}},
+                "classid":"5002"},
+            "success":true}}"#;
+        let result = from_slice::<Response>(json).unwrap().result;
+        assert!(result.success);
+        let info = &result.classes["5002"];
+        assert_eq!(info.class_id, 5002);
+        assert_eq!(info.instance_id, None);
+        assert!(info.tradable && info.commodity);
+        assert!(info.descriptions.is_empty());
+        assert_eq!(info.tags[1].name, "Craft Item");
+        assert_eq!(
+            info.icon_image_url(),
+            "https://community.cloudflare.steamstatic.com/economy/image/fWFc82js0fmoRAP"
+        );
+    }
+
+    #[test]
+    fn request_order() {
+        let json = br#"{"result":{
+            "5002":{"name":"Refined Metal","tradable":"1","marketable":"1","commodity":"1","classid":"5002"},
+            "5001":{"name":"Reclaimed Metal","tradable":"1","marketable":"1","commodity":"1","classid":"5001"},
+            "5000_0":{"name":"Scrap Metal","tradable":"1","marketable":"1","commodity":"1",
+                "classid":"5000","instanceid":"0"},
+            "success":true}}"#;
+        let result = from_slice::<Response>(json).unwrap().result;
+        let infos = in_request_order(result.classes, &[(5001, None), (5000, Some(0)), (5002, None)]);
+        let names: Vec<&str> = infos.iter().map(|info| info.name.as_str()).collect();
+        assert_eq!(names, ["Reclaimed Metal", "Scrap Metal", "Refined Metal"]);
+    }
+
+    #[test]
+    fn tf2_refined_metal() {
+        let client = SteamClient::with_api_key(&env::var("STEAM_API_KEY").unwrap());
+        let app_id = NonZeroU32::new(440).unwrap();
+        let infos = block_on(client.get_asset_class_info(app_id, &[(5002, None)], None)).unwrap();
+        assert_eq!(infos.len(), 1);
+    }
+}
@@ -0,0 +1,149 @@
+use std::collections::HashMap;
+use std::num::NonZeroU32;
+
+use crate::error::Error;
+use crate::params::Params;
+use crate::utils::{u64_from_str, Result, AUTHORITY};
+use crate::SteamClient;
+
+use hyper::body::to_bytes;
+use hyper::Uri;
+
+use serde::Deserialize;
+use serde_json::from_slice;
+
+const PATH: &str = "/ISteamEconomy/GetAssetPrices/v1/";
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct AssetClassProperty {
+    /// For example `def_index`
+    pub name: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct AssetPrice {
+    /// Name of the asset in the store (usually the class id)
+    pub name: String,
+    /// Date the asset was added to the store
+    pub date: String,
+    #[serde(rename = "classid")]
+    #[serde(deserialize_with = "u64_from_str")]
+    pub class_id: u64,
+    /// Prices in the smallest unit of the currency keyed by the currency
+    /// code, for example `"USD"`.
+    pub prices: HashMap<String, u64>,
+    /// Prices before the current sale
+    pub original_prices: Option<HashMap<String, u64>>,
+    /// Properties that identify the class in the game
+    #[serde(default)]
+    pub class: Vec<AssetClassProperty>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct PricesResult {
+    success: bool,
+    error: Option<String>,
+    #[serde(default)]
+    assets: Vec<AssetPrice>,
+}
+
+#[derive(Deserialize)]
+struct Response {
+    result: PricesResult,
+}
+
+impl SteamClient {
+    /// Gets the [AssetPrices](AssetPrice) of the items in the in-game store
+    ///
+    /// Requires an API key. If `currency` (for example `"EUR"`) is provided
+    /// only prices in that currency are returned.
+    pub async fn get_asset_prices(
+        &self,
+        app_id: NonZeroU32,
+        currency: Option<&str>,
+        language: Option<&str>,
+    ) -> Result<Vec<AssetPrice>> {
+        let api_key = self
+            .api_key
+            .as_ref()
+            .ok_or_else(|| Error::client("API key required"))?;
+
+        let query = Params::new()
+            .add("key", api_key)
+            .add("appid", app_id)
+            .add_optional("currency", currency)
+            .add_optional("language", language)
+            .encode();
+        let uri = Uri::builder()
+            .scheme("https")
+            .authority(AUTHORITY)
+            .path_and_query(format!("{}?{}", PATH, query))
+            .build()?;
+
+        let response = self.client.get(uri).await?;
+        let body = response.into_body();
+        let parsed = from_slice::<Response>(&to_bytes(body).await?)?;
+
+        let PricesResult {
+            success,
+            error,
+            assets,
+        } = parsed.result;
+
+        if success {
+            Ok(assets)
+        } else {
+            Err(Error::Client(format!(
+                "request failed: {}",
+                error.unwrap_or_else(|| "no message".to_owned())
+            )))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use tokio_test::block_on;
+
+    #[test]
+    fn parse_prices() {
+        let json = br#"{"result":{"success":true,"assets":[
+            {"prices":{"USD":249,"GBP":199,"EUR":229},"original_prices":{"USD":349,"GBP":279,"EUR":319},
+                "name":"5002","date":"2011-09-30","class":[{"name":"def_index","value":"5002"}],
+                "classid":"5002","tags":["Tools"],"tag_ids":["1"]},
+            {"prices":{"USD":99,"GBP":79,"EUR":89},"name":"5021","date":"2012-01-31",
+                "class":[{"name":"def_index","value":"5021"},{"name":"quality","value":"6"}],
+                "classid":"5021"}],
+            "tags":{"Tools":"Tools"},"tag_ids":{"1":"Tools"}}}"#;
+        let result = from_slice::<Response>(json).unwrap().result;
+        assert!(result.success);
+        let price = &result.assets[0];
+        assert_eq!(price.class_id, 5002);
+        assert_eq!(price.prices["EUR"], 229);
+        assert_eq!(price.original_prices.as_ref().map(|prices| prices["USD"]), Some(349));
+        assert_eq!(price.class[0].name, "def_index");
+        assert_eq!(price.tags, ["Tools"]);
+        let price = &result.assets[1];
+        assert_eq!(price.prices.len(), 3);
+        assert_eq!(price.original_prices, None);
+        assert_eq!(price.class[1].value, "6");
+        assert!(price.tags.is_empty());
+    }
+
+    #[test]
+    fn tf2_prices_in_euros() {
+        let client = SteamClient::with_api_key(&env::var("STEAM_API_KEY").unwrap());
+        let app_id = NonZeroU32::new(440).unwrap();
+        let prices = block_on(client.get_asset_prices(app_id, Some("EUR"), None)).unwrap();
+        assert!(prices
+            .iter()
+            .all(|price| price.prices.keys().all(|currency| currency == "EUR")));
+    }
+}
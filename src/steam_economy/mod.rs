@@ -0,0 +1,7 @@
+//! Implementations for the ISteamEconomy interface
+
+mod get_asset_class_info;
+mod get_asset_prices;
+
+pub use get_asset_class_info::{AssetClassInfo, AssetDescription, AssetTag};
+pub use get_asset_prices::{AssetClassProperty, AssetPrice};
@@ -1,14 +1,30 @@
-use crate::error::Error;
-use std::convert::TryFrom;
-use std::fmt;
-use std::num::ParseIntError;
-use std::str::FromStr;
+//! Steam ID types and conversions between their representations.
+//!
+//! The module doesn't depend on the HTTP client, so it's available with
+//! `default-features = false` and in `no_std` environments with `alloc`.
+
+use alloc::format;
+use alloc::string::String;
+use core::convert::TryFrom;
+use core::fmt;
+use core::num::ParseIntError;
+use core::str::FromStr;
 
 use serde::Deserialize;
 
-#[derive(Debug)]
+/// Error returned when a string can't be parsed as a steam id.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SteamError;
 
+impl fmt::Display for SteamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid SteamID")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SteamError {}
+
 impl From<ParseIntError> for SteamError {
     fn from(_: ParseIntError) -> Self {
         SteamError
@@ -21,9 +37,177 @@ impl From<ParseIntError> for SteamError {
 pub struct SteamID {
     universe: u8,
     account_type: u8,
+    instance: u32,
     account_id: u32,
 }
 
+const COMMUNITY_URL: &str = "https://steamcommunity.com";
+
+const ACCOUNT_TYPE_INDIVIDUAL: u8 = 1;
+const ACCOUNT_TYPE_GAME_SERVER: u8 = 3;
+const ACCOUNT_TYPE_ANON_GAME_SERVER: u8 = 4;
+const ACCOUNT_TYPE_CLAN: u8 = 7;
+const ACCOUNT_TYPE_CHAT: u8 = 8;
+const ACCOUNT_TYPE_ANON_USER: u8 = 10;
+
+/// Highest instance an individual account can have (web instance)
+const MAX_INDIVIDUAL_INSTANCE: u32 = 4;
+
+const CHAT_INSTANCE_FLAG_CLAN: u32 = 0x80000;
+const CHAT_INSTANCE_FLAG_LOBBY: u32 = 0x40000;
+
+impl SteamID {
+    /// Creates an ID from its parts.
+    ///
+    /// Only the lowest 20 bits of the instance are used.
+    pub const fn new(universe: u8, account_type: u8, instance: u32, account_id: u32) -> Self {
+        SteamID {
+            universe,
+            account_type,
+            instance: instance & 0xFFFFF,
+            account_id,
+        }
+    }
+
+    /// Creates an ID from the 64-bit representation.
+    pub const fn from_id64(value: u64) -> Self {
+        SteamID {
+            universe: (value >> 56) as u8,
+            account_type: (value << 8 >> 60) as u8,
+            instance: (value >> 32) as u32 & 0xFFFFF,
+            account_id: value as u32,
+        }
+    }
+
+    /// Returns the 64-bit representation of the ID.
+    pub const fn to_id64(&self) -> u64 {
+        let universe = (self.universe as u64) << 56;
+        let account_type = (self.account_type as u64) << 52;
+        let instance = (self.instance as u64) << 32;
+        let account_id = self.account_id as u64;
+        universe | account_type | instance | account_id
+    }
+
+    /// Returns the universe the account belongs to (1 is public).
+    pub const fn universe(&self) -> u8 {
+        self.universe
+    }
+
+    /// Returns the numeric account type (1 is individual, 7 is clan).
+    pub const fn account_type(&self) -> u8 {
+        self.account_type
+    }
+
+    /// Returns the instance of the account.
+    pub const fn instance(&self) -> u32 {
+        self.instance
+    }
+
+    /// Returns the 32-bit account ID.
+    ///
+    /// This is the ID used by the legacy community pages (for example
+    /// `/gid/<id>`) and it's also the last part of the [SteamID3].
+    pub const fn account_id(&self) -> u32 {
+        self.account_id
+    }
+
+    /// Checks that the ID could belong to an existing account.
+    ///
+    /// The check is done locally, so an ID passing it doesn't mean that an
+    /// account with the ID exists. IDs failing it will never match an account.
+    pub const fn is_valid(&self) -> bool {
+        if self.universe < 1 || self.universe > 4 || self.account_type == 0 {
+            return false;
+        }
+        match self.account_type {
+            ACCOUNT_TYPE_INDIVIDUAL => {
+                self.account_id != 0 && self.instance <= MAX_INDIVIDUAL_INSTANCE
+            }
+            ACCOUNT_TYPE_CLAN => self.account_id != 0 && self.instance == 0,
+            ACCOUNT_TYPE_GAME_SERVER => self.account_id != 0,
+            account_type => account_type <= ACCOUNT_TYPE_ANON_USER,
+        }
+    }
+
+    /// Is the ID an individual user account
+    pub const fn is_individual(&self) -> bool {
+        self.account_type == ACCOUNT_TYPE_INDIVIDUAL
+    }
+
+    /// Is the ID a clan (group)
+    pub const fn is_clan(&self) -> bool {
+        self.account_type == ACCOUNT_TYPE_CLAN
+    }
+
+    /// Is the ID a chat room, including clan chats and lobbies
+    pub const fn is_chat(&self) -> bool {
+        self.account_type == ACCOUNT_TYPE_CHAT
+    }
+
+    /// Is the ID a persistent or anonymous game server
+    pub const fn is_game_server(&self) -> bool {
+        self.account_type == ACCOUNT_TYPE_GAME_SERVER
+            || self.account_type == ACCOUNT_TYPE_ANON_GAME_SERVER
+    }
+
+    /// Is the ID an anonymous game server or an anonymous user
+    pub const fn is_anonymous(&self) -> bool {
+        self.account_type == ACCOUNT_TYPE_ANON_GAME_SERVER
+            || self.account_type == ACCOUNT_TYPE_ANON_USER
+    }
+
+    /// Returns the URL of the community profile of the account.
+    pub fn profile_url(&self) -> String {
+        format!("{}/profiles/{}", COMMUNITY_URL, self)
+    }
+
+    /// Returns the URL of the community page of the group.
+    pub fn group_url(&self) -> String {
+        format!("{}/gid/{}", COMMUNITY_URL, self.account_id)
+    }
+
+    /// Converts clan (group) ID to the ID of the clans chat room.
+    ///
+    /// Returns `None` if the ID doesn't belong to a clan.
+    pub const fn to_clan_chat(&self) -> Option<SteamID> {
+        if self.account_type != ACCOUNT_TYPE_CLAN {
+            return None;
+        }
+        Some(SteamID {
+            universe: self.universe,
+            account_type: ACCOUNT_TYPE_CHAT,
+            instance: CHAT_INSTANCE_FLAG_CLAN,
+            account_id: self.account_id,
+        })
+    }
+
+    /// Converts clan chat room ID back to the ID of the clan.
+    ///
+    /// Returns `None` if the ID isn't a clan chat.
+    pub const fn to_clan(&self) -> Option<SteamID> {
+        if self.account_type != ACCOUNT_TYPE_CHAT || self.instance & CHAT_INSTANCE_FLAG_CLAN == 0 {
+            return None;
+        }
+        Some(SteamID {
+            universe: self.universe,
+            account_type: ACCOUNT_TYPE_CLAN,
+            instance: 0,
+            account_id: self.account_id,
+        })
+    }
+}
+
+/// Serialized as the 64-bit ID in a string, same as the API returns it.
+#[cfg(feature = "serde-serialize")]
+impl serde::Serialize for SteamID {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
 impl fmt::Display for SteamID {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let id64: u64 = self.into();
@@ -34,33 +218,20 @@ impl fmt::Display for SteamID {
 #[allow(clippy::from_over_into)]
 impl Into<u64> for &SteamID {
     fn into(self) -> u64 {
-        let universe = (self.universe as u64) << 56;
-        let account_type = (self.account_type as u64) << 52;
-        let instance = 1_u64 << 32;
-        let account_id = self.account_id as u64;
-        universe | account_type | instance | account_id
+        self.to_id64()
     }
 }
 
 impl From<u64> for SteamID {
     fn from(value: u64) -> Self {
-        let universe = (value >> 56) as u8;
-        let account_type = (value << 8 >> 60) as u8;
-        let account_id = value as u32;
-        SteamID {
-            universe,
-            account_type,
-            account_id,
-        }
+        SteamID::from_id64(value)
     }
 }
 
 impl TryFrom<String> for SteamID {
-    type Error = Error;
+    type Error = SteamError;
     fn try_from(s: String) -> Result<Self, Self::Error> {
-        s.parse::<u64>()
-            .map(SteamID::from)
-            .map_err(|_| Error::Client("invalid SteamID".to_owned()))
+        Ok(SteamID::from(s.parse::<u64>()?))
     }
 }
 
@@ -118,6 +289,7 @@ impl FromStr for SteamID2 {
         Ok(SteamID2(SteamID {
             universe: if universe == 0 { 1 } else { universe },
             account_type: 1, // SteamID2 can only represent individual accounts
+            instance: 1,
             account_id,
         }))
     }
@@ -131,6 +303,7 @@ impl fmt::Display for SteamID3 {
         let SteamID {
             account_id,
             account_type,
+            instance,
             universe,
         } = self.0;
         let account_type_letter = match account_type {
@@ -142,6 +315,8 @@ impl fmt::Display for SteamID3 {
             5 => Some('P'),
             6 => Some('C'),
             7 => Some('g'),
+            8 if instance & CHAT_INSTANCE_FLAG_CLAN != 0 => Some('c'),
+            8 if instance & CHAT_INSTANCE_FLAG_LOBBY != 0 => Some('L'),
             8 => Some('T'),
             10 => Some('a'),
             _ => None,
@@ -163,17 +338,19 @@ impl From<SteamID> for SteamID3 {
 impl FromStr for SteamID3 {
     type Err = SteamError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let account_type: u8 = match &s[1..2] {
-            "I" => Ok(0),
-            "U" => Ok(1),
-            "M" => Ok(2),
-            "G" => Ok(3),
-            "A" => Ok(4),
-            "P" => Ok(5),
-            "C" => Ok(6),
-            "g" => Ok(7),
-            "T" | "L" | "c" => Ok(8),
-            "a" => Ok(10),
+        let (account_type, instance): (u8, u32) = match &s[1..2] {
+            "I" => Ok((0, 0)),
+            "U" => Ok((1, 1)),
+            "M" => Ok((2, 0)),
+            "G" => Ok((3, 0)),
+            "A" => Ok((4, 0)),
+            "P" => Ok((5, 0)),
+            "C" => Ok((6, 0)),
+            "g" => Ok((7, 0)),
+            "T" => Ok((8, 0)),
+            "L" => Ok((8, CHAT_INSTANCE_FLAG_LOBBY)),
+            "c" => Ok((8, CHAT_INSTANCE_FLAG_CLAN)),
+            "a" => Ok((10, 0)),
             _ => Err(SteamError),
         }?;
         let universe: u8 = s[3..4].parse()?;
@@ -181,6 +358,7 @@ impl FromStr for SteamID3 {
         Ok(SteamID3(SteamID {
             universe,
             account_type,
+            instance,
             account_id,
         }))
     }
@@ -189,17 +367,17 @@ impl FromStr for SteamID3 {
 #[cfg(test)]
 mod tests {
     use crate::steam_id::*;
-    const CORRECT_ID: SteamID = SteamID {
-        universe: 1,
-        account_type: 1,
-        account_id: 101006054,
-    };
+    const CORRECT_ID: SteamID = SteamID::new(1, 1, 1, 101006054);
+    const GROUP_ID: u64 = 103582791456670032;
 
     #[test]
     fn id64() {
         let id64: u64 = (&CORRECT_ID).into();
         assert_eq!(SteamID::from(76561198061271782), CORRECT_ID);
-        assert_eq!(id64, 76561198061271782)
+        assert_eq!(id64, 76561198061271782);
+        const FROM_ID64: SteamID = SteamID::from_id64(76561198061271782);
+        assert_eq!(FROM_ID64, CORRECT_ID);
+        assert_eq!(CORRECT_ID.to_id64(), 76561198061271782);
     }
 
     #[test]
@@ -216,4 +394,41 @@ mod tests {
         assert_eq!("[U:1:101006054]".parse::<SteamID3>().unwrap(), id3);
         assert_eq!(format!("{}", id3), "[U:1:101006054]");
     }
+
+    #[test]
+    fn community_urls() {
+        assert_eq!(
+            CORRECT_ID.profile_url(),
+            "https://steamcommunity.com/profiles/76561198061271782"
+        );
+        let group = SteamID::from(GROUP_ID);
+        assert_eq!(group.to_id64(), GROUP_ID);
+        assert_eq!(group.account_id(), 27148624);
+        assert_eq!(group.group_url(), "https://steamcommunity.com/gid/27148624");
+    }
+
+    #[test]
+    fn clan_chat() {
+        let group = SteamID::from(GROUP_ID);
+        let chat = group.to_clan_chat().unwrap();
+        assert_eq!(format!("{}", SteamID3::from(chat)), "[c:1:27148624]");
+        assert_eq!("[c:1:27148624]".parse::<SteamID3>().unwrap(), SteamID3(chat));
+        assert_eq!(chat.to_clan(), Some(group));
+        assert_eq!(CORRECT_ID.to_clan_chat(), None);
+        assert_eq!(group.to_clan(), None);
+    }
+
+    #[test]
+    fn validity() {
+        let group = SteamID::from(GROUP_ID);
+        assert!(CORRECT_ID.is_valid());
+        assert!(CORRECT_ID.is_individual());
+        assert!(group.is_valid());
+        assert!(group.is_clan());
+        assert!(group.to_clan_chat().unwrap().is_chat());
+        assert!(!SteamID::from(7656119806127178).is_valid());
+        assert!(!SteamID::from(76561197960265728).is_valid());
+        assert!(!CORRECT_ID.is_game_server() && !CORRECT_ID.is_anonymous());
+        assert!("[A:1:123]".parse::<SteamID3>().map(SteamID::from).unwrap().is_anonymous());
+    }
 }
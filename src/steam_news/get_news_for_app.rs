@@ -1,3 +1,6 @@
+use crate::params::Params;
+#[cfg(feature = "serde-serialize")]
+use crate::utils::u64_to_str;
 use crate::utils::{u64_from_str, Result, AUTHORITY};
 use crate::SteamClient;
 
@@ -9,10 +12,12 @@ use serde_json::from_slice;
 
 const PATH: &str = "/ISteamNews/GetNewsForApp/v0002/";
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
 pub struct NewsItem {
     #[serde(rename = "gid")]
     #[serde(deserialize_with = "u64_from_str")]
+    #[cfg_attr(feature = "serde-serialize", serde(serialize_with = "u64_to_str"))]
     pub id: u64,
     pub title: String,
     pub url: String,
@@ -32,6 +37,14 @@ pub struct NewsItem {
     pub tags: Option<Vec<String>>,
 }
 
+#[cfg(feature = "time")]
+impl NewsItem {
+    /// [date](NewsItem::date) as [OffsetDateTime](time::OffsetDateTime)
+    pub fn datetime(&self) -> time::OffsetDateTime {
+        crate::timestamp::from_unix(self.date)
+    }
+}
+
 #[derive(Debug, Deserialize)]
 struct Appnews {
     #[serde(rename = "appid")]
@@ -47,6 +60,11 @@ struct Response {
 }
 
 impl SteamClient {
+    /// Returns the latest [NewsItems](NewsItem) of the app
+    ///
+    /// `end_date` is an unix timestamp, with the `time` feature
+    /// `get_news_for_app_until` takes an `OffsetDateTime` instead. Works
+    /// without an API key.
     pub async fn get_news_for_app(
         &self,
         app_id: u32,
@@ -56,17 +74,14 @@ impl SteamClient {
         feeds: Vec<String>,
         tags: Vec<String>,
     ) -> Result<Vec<NewsItem>> {
-        let query_content = optional_query!(content_len, "maxlength");
-        let query_date = optional_query!(end_date, "enddate");
-        let query_count = optional_query!(count);
-        let query_feeds = vec_query!(feeds);
-        let query_tags = vec_query!(tags, "tags");
-
-        let query = format!(
-            "appid={}{}{}{}{}{}",
-            app_id, query_content, query_date, query_count, query_feeds, query_tags
-        );
-
+        let query = Params::new()
+            .add("appid", app_id)
+            .add_optional("maxlength", content_len)
+            .add_optional("enddate", end_date)
+            .add_optional("count", count)
+            .add_joined("feeds", feeds)
+            .add_joined("tags", tags)
+            .encode();
         let uri = Uri::builder()
             .scheme("https")
             .authority(AUTHORITY)
@@ -79,6 +94,23 @@ impl SteamClient {
 
         Ok(response.appnews.newsitems)
     }
+
+    /// Same as [get_news_for_app](SteamClient::get_news_for_app) but returns
+    /// the news published before `end_date`
+    #[cfg(feature = "time")]
+    pub async fn get_news_for_app_until(
+        &self,
+        app_id: u32,
+        content_len: Option<u32>,
+        end_date: time::OffsetDateTime,
+        count: Option<u32>,
+        feeds: Vec<String>,
+        tags: Vec<String>,
+    ) -> Result<Vec<NewsItem>> {
+        let end_date = crate::timestamp::to_unix(end_date);
+        self.get_news_for_app(app_id, content_len, Some(end_date), count, feeds, tags)
+            .await
+    }
 }
 
 #[cfg(test)]
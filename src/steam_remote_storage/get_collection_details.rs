@@ -0,0 +1,107 @@
+use crate::params::Params;
+use crate::utils::{u64_from_str, Result, ResponseWrapper};
+use crate::SteamClient;
+
+use serde::Deserialize;
+use serde_json::from_slice;
+
+const PATH: &str = "/ISteamRemoteStorage/GetCollectionDetails/v1/";
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct CollectionItem {
+    #[serde(rename = "publishedfileid")]
+    #[serde(deserialize_with = "u64_from_str")]
+    #[cfg_attr(feature = "serde-serialize", serde(serialize_with = "crate::utils::u64_to_str"))]
+    pub id: u64,
+    #[serde(rename = "sortorder")]
+    pub sort_order: u32,
+    /// `0` for items, `2` for nested collections
+    #[serde(rename = "filetype", alias = "file_type")]
+    pub file_type: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct Collection {
+    #[serde(rename = "publishedfileid")]
+    #[serde(deserialize_with = "u64_from_str")]
+    #[cfg_attr(feature = "serde-serialize", serde(serialize_with = "crate::utils::u64_to_str"))]
+    pub id: u64,
+    /// Items of the collection in the order set by its author
+    #[serde(default)]
+    pub children: Vec<CollectionItem>,
+}
+
+#[derive(Deserialize)]
+struct CollectionResult {
+    result: u32,
+    #[serde(flatten)]
+    collection: Collection,
+}
+
+#[derive(Deserialize)]
+struct Response {
+    #[serde(rename = "collectiondetails")]
+    #[serde(default)]
+    collections: Vec<CollectionResult>,
+}
+
+impl SteamClient {
+    /// Gets the [Collections](Collection) for the given workshop collection ids
+    ///
+    /// Doesn't require an API key. Collections that don't exist are left out
+    /// of the result, ids of regular items return a collection without children.
+    pub async fn get_collection_details(&self, ids: &[u64]) -> Result<Vec<Collection>> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let params = Params::new()
+            .add("collectioncount", ids.len())
+            .add_list("publishedfileids", ids);
+
+        let body = self.post_form(PATH, &params).await?;
+        let response = from_slice::<ResponseWrapper<Response>>(&body)?.response;
+
+        let mut collections: Vec<Collection> = response
+            .collections
+            .into_iter()
+            .filter(|result| result.result == 1)
+            .map(|result| result.collection)
+            .collect();
+        for collection in &mut collections {
+            collection.children.sort_by_key(|item| item.sort_order);
+        }
+        Ok(collections)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio_test::block_on;
+
+    #[test]
+    fn parse_details() {
+        let json = br#"{"response":{"result":1,"resultcount":2,"collectiondetails":[
+            {"publishedfileid":"532500598","result":1,"children":[
+                {"publishedfileid":"1234","sortorder":2,"filetype":0},
+                {"publishedfileid":"5678","sortorder":1,"filetype":2}]},
+            {"publishedfileid":"1","result":9}]}}"#;
+        let response = from_slice::<ResponseWrapper<Response>>(json).unwrap().response;
+        assert_eq!(response.collections[1].result, 9);
+        let collection = &response.collections[0].collection;
+        assert_eq!(collection.id, 532500598);
+        assert_eq!(collection.children[1].id, 5678);
+        assert_eq!(collection.children[1].file_type, 2);
+        assert!(response.collections[1].collection.children.is_empty());
+    }
+
+    #[test]
+    fn missing_collection() {
+        let client = SteamClient::new();
+        let collections = block_on(client.get_collection_details(&[1])).unwrap();
+        assert!(collections.is_empty());
+    }
+}
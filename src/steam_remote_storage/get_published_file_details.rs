@@ -0,0 +1,153 @@
+use std::result::Result as StdResult;
+
+use crate::params::Params;
+use crate::steam_id::SteamID;
+use crate::utils::{found_results, u64_from_str, Result, ResponseWrapper};
+use crate::SteamClient;
+
+use serde::{Deserialize, Deserializer};
+use serde_aux::field_attributes::deserialize_number_from_string;
+use serde_json::{from_slice, Value};
+
+const PATH: &str = "/ISteamRemoteStorage/GetPublishedFileDetails/v1/";
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct PublishedFile {
+    #[serde(rename = "publishedfileid")]
+    #[serde(deserialize_with = "u64_from_str")]
+    #[cfg_attr(feature = "serde-serialize", serde(serialize_with = "crate::utils::u64_to_str"))]
+    pub id: u64,
+    pub creator: SteamID,
+    /// App used to upload the file
+    pub creator_app_id: u32,
+    /// App the file belongs to
+    pub consumer_app_id: u32,
+    pub title: String,
+    pub description: String,
+    /// Size in bytes
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub file_size: u64,
+    /// Empty for files hosted on the content servers
+    #[serde(default)]
+    pub file_url: String,
+    #[serde(default)]
+    pub preview_url: String,
+    #[serde(default, deserialize_with = "tags_from_objects")]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub subscriptions: u32,
+    #[serde(default)]
+    pub favorited: u32,
+    #[serde(default)]
+    pub lifetime_subscriptions: u32,
+    #[serde(default)]
+    pub views: u32,
+    /// Unix timestamp of the upload
+    pub time_created: u32,
+    /// Unix timestamp of the last update
+    pub time_updated: u32,
+}
+
+#[cfg(feature = "time")]
+impl PublishedFile {
+    /// [time_created](PublishedFile::time_created) as [OffsetDateTime](time::OffsetDateTime)
+    pub fn time_created_datetime(&self) -> time::OffsetDateTime {
+        crate::timestamp::from_unix(self.time_created)
+    }
+
+    /// [time_updated](PublishedFile::time_updated) as [OffsetDateTime](time::OffsetDateTime)
+    pub fn time_updated_datetime(&self) -> time::OffsetDateTime {
+        crate::timestamp::from_unix(self.time_updated)
+    }
+}
+
+#[derive(Deserialize)]
+struct Tag {
+    tag: String,
+}
+
+/// Tags are returned as `[{"tag": "..."}]`
+fn tags_from_objects<'de, D>(deserializer: D) -> StdResult<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let tags = Vec::<Tag>::deserialize(deserializer)?;
+    Ok(tags.into_iter().map(|tag| tag.tag).collect())
+}
+
+#[derive(Deserialize)]
+struct Response {
+    #[serde(rename = "publishedfiledetails")]
+    #[serde(default)]
+    files: Vec<Value>,
+}
+
+impl SteamClient {
+    /// Gets the [PublishedFiles](PublishedFile) for the given workshop item ids
+    ///
+    /// Doesn't require an API key. Items that don't exist or aren't visible
+    /// are left out of the result.
+    pub async fn get_published_file_details(&self, ids: &[u64]) -> Result<Vec<PublishedFile>> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let params = Params::new()
+            .add("itemcount", ids.len())
+            .add_list("publishedfileids", ids);
+
+        let body = self.post_form(PATH, &params).await?;
+        let response = from_slice::<ResponseWrapper<Response>>(&body)?.response;
+
+        found_results(response.files)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Error;
+    use tokio_test::block_on;
+
+    #[test]
+    fn parse_details() {
+        let json = br#"{"response":{"result":1,"resultcount":2,"publishedfiledetails":[
+            {"publishedfileid":"2503622437","result":1,"creator":"76561198011965365","creator_app_id":766,
+                "consumer_app_id":4000,"filename":"","file_size":"3461424","file_url":"",
+                "hcontent_file":"4453446102","preview_url":"https://steamuserimages-a.akamaihd.net/ugc/1/","hcontent_preview":"1",
+                "title":"Map","description":"A map","time_created":1624386523,"time_updated":1625000000,
+                "visibility":0,"banned":0,"ban_reason":"","subscriptions":1500,"favorited":30,
+                "lifetime_subscriptions":2000,"lifetime_favorited":35,"views":4000,
+                "tags":[{"tag":"Addon"},{"tag":"Map"}]},
+            {"publishedfileid":"1","result":9}]}}"#;
+        let response = from_slice::<ResponseWrapper<Response>>(json).unwrap().response;
+        let files = found_results::<PublishedFile>(response.files).unwrap();
+        assert_eq!(files.len(), 1);
+        let file = &files[0];
+        assert_eq!(file.id, 2503622437);
+        assert_eq!(file.creator, SteamID::from(76561198011965365));
+        assert_eq!(file.file_size, 3461424);
+        assert_eq!(file.tags, ["Addon", "Map"]);
+        assert_eq!(file.lifetime_subscriptions, 2000);
+    }
+
+    #[test]
+    fn malformed_file() {
+        let json = br#"{"response":{"result":1,"resultcount":2,"publishedfiledetails":[
+            {"publishedfileid":"2503622437","result":1,"creator":"76561198011965365","title":"Map"},
+            {"publishedfileid":"1","result":9}]}}"#;
+        let response = from_slice::<ResponseWrapper<Response>>(json).unwrap().response;
+        assert!(matches!(
+            found_results::<PublishedFile>(response.files),
+            Err(Error::JsonParser(_))
+        ));
+    }
+
+    #[test]
+    fn missing_item() {
+        let client = SteamClient::new();
+        let files = block_on(client.get_published_file_details(&[1])).unwrap();
+        assert!(files.is_empty());
+    }
+}
@@ -0,0 +1,7 @@
+//! Implementations for the ISteamRemoteStorage interface
+
+mod get_collection_details;
+mod get_published_file_details;
+
+pub use get_collection_details::{Collection, CollectionItem};
+pub use get_published_file_details::PublishedFile;
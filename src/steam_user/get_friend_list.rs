@@ -2,15 +2,17 @@ use std::fmt;
 
 use crate::client::SteamClient;
 use crate::error::Error;
+use crate::params::Params;
 use crate::steam_id::SteamID;
-use crate::utils::{Result, AUTHORITY};
+use crate::utils::{check_ids, Result, AUTHORITY};
 use hyper::body::to_bytes;
 use hyper::Uri;
 use serde::Deserialize;
 
 const PATH: &str = "/ISteamUser/GetFriendList/v1";
 
-#[derive(Debug, PartialEq, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
 #[serde(rename_all = "lowercase")]
 pub enum Relation {
     Friend,
@@ -27,14 +29,24 @@ impl fmt::Display for Relation {
     }
 }
 
-#[derive(Deserialize, Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
 pub struct Friend {
     #[serde(rename = "steamid")]
     pub id: SteamID,
     pub relationship: Relation,
+    /// Unix timestamp of when the friendship started
     pub friend_since: u32,
 }
 
+#[cfg(feature = "time")]
+impl Friend {
+    /// [friend_since](Friend::friend_since) as [OffsetDateTime](time::OffsetDateTime)
+    pub fn friend_since_datetime(&self) -> time::OffsetDateTime {
+        crate::timestamp::from_unix(self.friend_since)
+    }
+}
+
 #[derive(Deserialize)]
 struct FriendsWrapper {
     friends: Vec<Friend>,
@@ -58,9 +70,13 @@ impl SteamClient {
             .api_key
             .as_ref()
             .ok_or_else(|| Error::client("API key required"))?;
+        check_ids(&[*id])?;
 
-        let relation = optional_query!(relationship);
-        let query = format!("key={}&steamid={}{}", api_key, id, relation);
+        let query = Params::new()
+            .add("key", api_key)
+            .add("steamid", id)
+            .add_optional("relationship", relationship)
+            .encode();
         let uri = Uri::builder()
             .scheme("https")
             .authority(AUTHORITY)
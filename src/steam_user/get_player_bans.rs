@@ -1,14 +1,16 @@
 use crate::client::SteamClient;
 use crate::error::Error;
+use crate::params::Params;
 use crate::steam_id::SteamID;
-use crate::utils::{PlayersWrapper, Result, AUTHORITY};
+use crate::utils::{check_ids, PlayersWrapper, Result, AUTHORITY};
 use hyper::body::to_bytes;
 use hyper::Uri;
 use serde::Deserialize;
 
 const PATH: &str = "/ISteamUser/GetPlayerBans/v1";
 
-#[derive(Debug, PartialEq, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
 #[serde(rename_all = "lowercase")]
 pub enum EconomyBanStatus {
     None,
@@ -16,7 +18,8 @@ pub enum EconomyBanStatus {
     Unknown,
 }
 
-#[derive(Debug, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
 pub struct BanData {
     #[serde(rename = "SteamId")]
     pub id: SteamID,
@@ -40,23 +43,23 @@ type Response = PlayersWrapper<BanData>;
 impl SteamClient {
     /// Gets vector of [BanData] structs
     ///
-    /// Requires an API key. If the [SteamID] is invalid or user doesn't exist
-    /// with the ID the API just drops the [BanData] from the response. So
+    /// Requires an API key. Returns an error without calling the API if any of
+    /// the [SteamIDs](SteamID) isn't [valid](SteamID::is_valid). If user doesn't
+    /// exist with the ID the API just drops the [BanData] from the response. So
     /// don't assume the returned [BanDatas](BanData) are in the same order as
     /// the [SteamIDs](SteamID). Always check the [SteamID] from the [BanData]
     /// struct
-    pub async fn get_player_bans(&self, ids: &Vec<SteamID>) -> Result<Vec<BanData>> {
+    pub async fn get_player_bans(&self, ids: &[SteamID]) -> Result<Vec<BanData>> {
         let api_key = self
             .api_key
             .as_ref()
             .ok_or_else(|| Error::client("API key required"))?;
+        check_ids(ids)?;
 
-        let id_query = ids
-            .iter()
-            .map(|id| id.to_string())
-            .collect::<Vec<String>>()
-            .join(",");
-        let query = format!("key={}&steamids={}", api_key, id_query);
+        let query = Params::new()
+            .add("key", api_key)
+            .add_joined("steamids", ids)
+            .encode();
         let uri = Uri::builder()
             .scheme("https")
             .authority(AUTHORITY)
@@ -79,7 +82,7 @@ mod tests {
     fn works() {
         let client = SteamClient::with_api_key(&env::var("STEAM_API_KEY").unwrap());
         let ban_data =
-            tokio_test::block_on(client.get_player_bans(&vec![SteamID::from(76561198061271782)]))
+            tokio_test::block_on(client.get_player_bans(&[SteamID::from(76561198061271782)]))
                 .unwrap();
         assert_eq!(
             ban_data,
@@ -2,19 +2,20 @@ use std::net::Ipv4Addr;
 
 use crate::client::SteamClient;
 use crate::error::Error;
+use crate::params::Params;
 use crate::steam_id::SteamID;
-use crate::utils::{
-    PlayersWrapper, ResponseWrapper, Result, AUTHORITY,
-};
+use crate::utils::{check_ids, PlayersWrapper, ResponseWrapper, Result, AUTHORITY};
 use hyper::body::to_bytes;
 use hyper::Uri;
 use serde::Deserialize;
 
 const PATH: &str = "/ISteamUser/GetPlayerSummaries/v0002/";
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize), serde(into = "u32"))]
 #[serde(from = "u32")]
 pub enum CommentPermission {
+    #[default]
     FriendsOnly = 0,
     Public = 1,
     Private = 2,
@@ -31,13 +32,14 @@ impl From<u32> for CommentPermission {
     }
 }
 
-impl Default for CommentPermission {
-    fn default() -> Self {
-        CommentPermission::FriendsOnly
+impl From<CommentPermission> for u32 {
+    fn from(value: CommentPermission) -> Self {
+        value as u32
     }
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize), serde(into = "u32"))]
 #[serde(from = "u32")]
 pub enum Visibility {
     Private = 1,
@@ -54,7 +56,14 @@ impl From<u32> for Visibility {
     }
 }
 
-#[derive(Debug, Deserialize)]
+impl From<Visibility> for u32 {
+    fn from(value: Visibility) -> Self {
+        value as u32
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize), serde(into = "u32"))]
 #[serde(from = "u32")]
 pub enum Status {
     Offline = 0,
@@ -81,7 +90,14 @@ impl From<u32> for Status {
     }
 }
 
-#[derive(Debug, Deserialize)]
+impl From<Status> for u32 {
+    fn from(value: Status) -> Self {
+        value as u32
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize), serde(into = "u32"))]
 #[serde(from = "u32")]
 pub enum ProfileState {
     Unconfigured = 0,
@@ -98,7 +114,14 @@ impl From<u32> for ProfileState {
     }
 }
 
-#[derive(Debug, Deserialize)]
+impl From<ProfileState> for u32 {
+    fn from(value: ProfileState) -> Self {
+        value as u32
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
 pub struct Summary {
     /// Steam ID of the user
     #[serde(rename = "steamid")]
@@ -151,6 +174,19 @@ pub struct Summary {
     pub gameserver_ip: Option<Ipv4Addr>,
 }
 
+#[cfg(feature = "time")]
+impl Summary {
+    /// [last_logoff](Summary::last_logoff) as [OffsetDateTime](time::OffsetDateTime)
+    pub fn last_logoff_datetime(&self) -> Option<time::OffsetDateTime> {
+        self.last_logoff.map(crate::timestamp::from_unix)
+    }
+
+    /// [time_created](Summary::time_created) as [OffsetDateTime](time::OffsetDateTime)
+    pub fn time_created_datetime(&self) -> Option<time::OffsetDateTime> {
+        self.time_created.map(crate::timestamp::from_unix)
+    }
+}
+
 /// Private Response type to simplify these utility types
 type Response = ResponseWrapper<PlayersWrapper<Summary>>;
 
@@ -158,25 +194,25 @@ impl SteamClient {
     /// Gets vector of player/account [Summaries](Summary).
     ///
     /// Requires an API key and works with maximum of 100 [SteamIDs](SteamID).
-    /// If the [SteamID] is invalid or user doesn't exist with the ID the API
+    /// Returns an error without calling the API if any of the [SteamIDs](SteamID)
+    /// isn't [valid](SteamID::is_valid). If user doesn't exist with the ID the API
     /// just drops the summary from the response. So don't assume the returned
     /// [Summaries](Summary) are in the same order as the [SteamIDs](SteamID).
     /// Always check the [SteamID] from the [Summary] struct.
-    pub async fn get_player_summaries(&self, ids: &Vec<SteamID>) -> Result<Vec<Summary>> {
+    pub async fn get_player_summaries(&self, ids: &[SteamID]) -> Result<Vec<Summary>> {
         let api_key = self
             .api_key
             .as_ref()
             .ok_or_else(|| Error::client("API key required"))?;
+        check_ids(ids)?;
 
         if ids.len() > 100 {
             return Err(Error::client("too many IDs (> 100)"));
         }
-        let id_query = ids
-            .iter()
-            .map(|id| id.to_string())
-            .collect::<Vec<String>>()
-            .join(",");
-        let query = format!("key={}&steamids={}", api_key, id_query);
+        let query = Params::new()
+            .add("key", api_key)
+            .add_joined("steamids", ids)
+            .encode();
         let uri = Uri::builder()
             .scheme("https")
             .authority(AUTHORITY)
@@ -200,7 +236,7 @@ mod tests {
     fn works_with_single() {
         let client = SteamClient::with_api_key(&env::var("STEAM_API_KEY").unwrap());
         let summary = tokio_test::block_on(
-            client.get_player_summaries(&vec![SteamID::from(76561198061271782)]),
+            client.get_player_summaries(&[SteamID::from(76561198061271782)]),
         )
         .unwrap();
         println!("{:?}", summary);
@@ -210,7 +246,7 @@ mod tests {
     #[test]
     fn works_with_multiple() {
         let client = SteamClient::with_api_key(&env::var("STEAM_API_KEY").unwrap());
-        let summary = tokio_test::block_on(client.get_player_summaries(&vec![
+        let summary = tokio_test::block_on(client.get_player_summaries(&[
             SteamID::from(76561198061271782),
             SteamID::from(76561198072766352),
         ]))
@@ -222,9 +258,30 @@ mod tests {
     fn works_with_invalid() {
         let client = SteamClient::with_api_key(&env::var("STEAM_API_KEY").unwrap());
         let summary = tokio_test::block_on(
-            client.get_player_summaries(&vec![SteamID::from(7656119806127178)]),
-        )
-        .unwrap();
-        assert!(summary.is_empty());
+            client.get_player_summaries(&[SteamID::from(7656119806127178)]),
+        );
+        assert!(summary.is_err());
+    }
+
+    #[cfg(feature = "serde-serialize")]
+    #[test]
+    fn serialize_round_trip() {
+        let json = r#"{
+            "steamid": "76561198061271782",
+            "communityvisibilitystate": 3,
+            "profilestate": 1,
+            "personaname": "petesammakko",
+            "commentpermission": 1,
+            "profileurl": "https://steamcommunity.com/id/petesammakko/",
+            "avatar": "https://avatars.steamstatic.com/a.jpg",
+            "avatarmedium": "https://avatars.steamstatic.com/a_medium.jpg",
+            "avatarfull": "https://avatars.steamstatic.com/a_full.jpg",
+            "personastate": 0,
+            "primaryclanid": "103582791429521408",
+            "timecreated": 1332524872
+        }"#;
+        let summary: Summary = serde_json::from_str(json).unwrap();
+        let serialized = serde_json::to_string(&summary).unwrap();
+        assert_eq!(serde_json::from_str::<Summary>(&serialized).unwrap(), summary);
     }
 }
@@ -1,5 +1,6 @@
 use crate::client::SteamClient;
 use crate::error::Error;
+use crate::params::Params;
 use crate::steam_id::SteamID;
 use crate::utils::{ResponseWrapper, Result, AUTHORITY};
 use hyper::body::to_bytes;
@@ -45,8 +46,11 @@ impl SteamClient {
             .as_ref()
             .ok_or_else(|| Error::client("API key required"))?;
 
-        let type_query = optional_query!(url_type);
-        let query = format!("key={}&vanityurl={}{}", api_key, vanity_url, type_query);
+        let query = Params::new()
+            .add("key", api_key)
+            .add("vanityurl", vanity_url)
+            .add_optional("url_type", url_type)
+            .encode();
         let uri = Uri::builder()
             .scheme("https")
             .authority(AUTHORITY)
@@ -0,0 +1,172 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::num::{NonZeroU32, NonZeroU64};
+
+use crate::steam_user_stats::{AchievementData, GameSchema, PlayerAchievements};
+use crate::utils::Result;
+use crate::{SteamClient, SteamID};
+
+use futures::try_join;
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct ReportedAchievement {
+    /// Name of the achievement as unlocalized token
+    pub api_name: String,
+    pub display_name: String,
+    pub description: Option<String>,
+    pub icon: String,
+    pub hidden: bool,
+    /// Percentage of all players who have unlocked the achievement
+    pub global_percent: f32,
+    pub achieved: bool,
+    /// Unix timestamp of the unlock, `None` if the achievement isn't unlocked
+    pub unlock_time: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct AchievementReport {
+    pub id: SteamID,
+    pub game_name: String,
+    /// All achievements of the game, rarest first
+    pub achievements: Vec<ReportedAchievement>,
+}
+
+impl AchievementReport {
+    fn new(schema: GameSchema, player: PlayerAchievements, global: Vec<AchievementData>) -> Self {
+        let unlocks: HashMap<String, u32> = player
+            .achievements
+            .into_iter()
+            .filter(|a| a.achieved)
+            .map(|a| (a.api_name, a.unlock_time))
+            .collect();
+        let percents: HashMap<String, f32> =
+            global.into_iter().map(|a| (a.name, a.percent)).collect();
+
+        let mut achievements: Vec<ReportedAchievement> = schema
+            .achievements
+            .into_iter()
+            .map(|a| ReportedAchievement {
+                global_percent: percents.get(&a.name).copied().unwrap_or_default(),
+                achieved: unlocks.contains_key(&a.name),
+                unlock_time: unlocks.get(&a.name).copied(),
+                api_name: a.name,
+                display_name: a.display_name,
+                description: a.description,
+                icon: a.icon,
+                hidden: a.hidden,
+            })
+            .collect();
+        achievements.sort_by(|a, b| {
+            a.global_percent
+                .partial_cmp(&b.global_percent)
+                .unwrap_or(Ordering::Equal)
+        });
+
+        AchievementReport {
+            id: player.id,
+            game_name: schema.game_name,
+            achievements,
+        }
+    }
+
+    /// Number of achievements the user has unlocked
+    pub fn unlocked(&self) -> usize {
+        self.achievements.iter().filter(|a| a.achieved).count()
+    }
+
+    /// Percentage of the games achievements the user has unlocked
+    pub fn completion_percent(&self) -> f32 {
+        if self.achievements.is_empty() {
+            return 0.0;
+        }
+        self.unlocked() as f32 / self.achievements.len() as f32 * 100.0
+    }
+
+    /// The unlocked achievement with the lowest global unlock percentage
+    pub fn rarest_unlocked(&self) -> Option<&ReportedAchievement> {
+        self.achievements.iter().find(|a| a.achieved)
+    }
+}
+
+impl SteamClient {
+    /// Combines the schema, the users unlocks and the global unlock percentages
+    /// of the game to an [AchievementReport]
+    ///
+    /// Requires an API key. Makes three requests concurrently, see
+    /// [get_schema_for_game](SteamClient::get_schema_for_game),
+    /// [get_player_achievements](SteamClient::get_player_achievements) and
+    /// [get_global_achievement_percentages_for_app](SteamClient::get_global_achievement_percentages_for_app).
+    pub async fn achievement_report(
+        &self,
+        id: &SteamID,
+        app_id: NonZeroU32,
+    ) -> Result<AchievementReport> {
+        let (schema, player, global) = try_join!(
+            self.get_schema_for_game(app_id, None),
+            self.get_player_achievements(id, app_id, None),
+            self.get_global_achievement_percentages_for_app(NonZeroU64::from(app_id)),
+        )?;
+
+        Ok(AchievementReport::new(schema, player, global))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::steam_user_stats::{AchievementSchema, PlayerAchievement, StatValue};
+
+    fn schema(name: &str) -> AchievementSchema {
+        AchievementSchema {
+            name: name.to_owned(),
+            default_value: StatValue::Int(0),
+            display_name: name.to_lowercase(),
+            hidden: false,
+            description: None,
+            icon: String::new(),
+            icon_gray: String::new(),
+        }
+    }
+
+    #[test]
+    fn merges_and_sorts() {
+        let id = SteamID::from(76561198061271782);
+        let schema = GameSchema {
+            game_name: "Test".to_owned(),
+            game_version: "1".to_owned(),
+            achievements: vec![schema("COMMON"), schema("RARE"), schema("RAREST")],
+            stats: vec![],
+        };
+        let player = PlayerAchievements {
+            id,
+            game_name: "Test".to_owned(),
+            achievements: ["COMMON", "RARE", "RAREST"]
+                .iter()
+                .map(|name| PlayerAchievement {
+                    api_name: name.to_string(),
+                    achieved: *name != "RAREST",
+                    unlock_time: if *name != "RAREST" { 1332524872 } else { 0 },
+                    name: None,
+                    description: None,
+                })
+                .collect(),
+        };
+        let global = [("COMMON", 80.5), ("RARE", 2.5), ("RAREST", 0.1)]
+            .iter()
+            .map(|(name, percent)| AchievementData {
+                name: name.to_string(),
+                percent: *percent,
+            })
+            .collect();
+
+        let report = AchievementReport::new(schema, player, global);
+        let order: Vec<&str> = report.achievements.iter().map(|a| a.api_name.as_str()).collect();
+        assert_eq!(order, ["RAREST", "RARE", "COMMON"]);
+        assert_eq!(report.unlocked(), 2);
+        assert_eq!(report.rarest_unlocked().unwrap().api_name, "RARE");
+        assert_eq!(report.achievements[0].unlock_time, None);
+        assert!((report.completion_percent() - 66.666).abs() < 0.01);
+    }
+}
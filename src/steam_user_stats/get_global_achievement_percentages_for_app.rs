@@ -2,18 +2,24 @@ use std::num::NonZeroU64;
 
 use crate::client::SteamClient;
 use crate::error::Error;
+use crate::params::Params;
 use crate::utils::{Result, AUTHORITY};
 use hyper::body::to_bytes;
 use hyper::Uri;
 use serde::Deserialize;
+use serde_aux::field_attributes::deserialize_number_from_string;
 
 const PATH: &str = "/ISteamUserStats/GetGlobalAchievementPercentagesForApp/v0002/";
 
-#[derive(Deserialize, Debug)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
 pub struct AchievementData {
     /// Name of the achievement as unlocalized token
     pub name: String,
-    // Percentage of players who have unlocked the achievement
+    /// Percentage of players who have unlocked the achievement
+    ///
+    /// The API returns it either as a number or as a string.
+    #[serde(deserialize_with = "deserialize_number_from_string")]
     pub percent: f32,
 }
 
@@ -35,7 +41,9 @@ impl SteamClient {
         &self,
         game_id: NonZeroU64,
     ) -> Result<Vec<AchievementData>> {
-        let query = format!("gameid={}", game_id);
+        let query = Params::new()
+            .add("gameid", game_id)
+            .encode();
         let uri = Uri::builder()
             .scheme("https")
             .authority(AUTHORITY)
@@ -58,8 +66,23 @@ impl SteamClient {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use serde_json::from_slice;
     use tokio_test::{assert_err, block_on};
 
+    #[test]
+    fn parse_number_and_string_percent() {
+        let json = br#"{"achievementpercentages":{"achievements":[
+            {"name":"COMMON","percent":80.5},
+            {"name":"RARE","percent":"2.5"}
+        ]}}"#;
+        let achievements = from_slice::<Response>(json)
+            .unwrap()
+            .achievementpercentages
+            .achievements;
+        assert_eq!(achievements[0].percent, 80.5);
+        assert_eq!(achievements[1].percent, 2.5);
+    }
+
     #[test]
     fn correct_csgo_achievements() {
         let client = SteamClient::new();
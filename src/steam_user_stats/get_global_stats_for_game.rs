@@ -0,0 +1,126 @@
+use std::collections::HashMap;
+use std::num::NonZeroU32;
+
+use crate::error::Error;
+use crate::params::Params;
+use crate::utils::{ResponseWrapper, Result, AUTHORITY};
+use crate::SteamClient;
+
+use hyper::body::to_bytes;
+use hyper::Uri;
+
+use serde::Deserialize;
+use serde_aux::field_attributes::deserialize_number_from_string;
+use serde_json::from_slice;
+
+const PATH: &str = "/ISteamUserStats/GetGlobalStatsForGame/v1/";
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct GlobalStatDay {
+    /// Unix timestamp of the day
+    pub date: u32,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub total: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct GlobalStat {
+    /// Aggregated total of the stat over all players
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub total: i64,
+    /// Daily totals, only available when a date range is requested
+    #[serde(default)]
+    pub history: Vec<GlobalStatDay>,
+}
+
+#[derive(Deserialize)]
+struct GlobalStats {
+    result: u32,
+    error: Option<String>,
+    #[serde(rename = "globalstats")]
+    global_stats: Option<HashMap<String, GlobalStat>>,
+}
+
+type Response = ResponseWrapper<GlobalStats>;
+
+impl GlobalStats {
+    fn into_stats(self) -> Result<HashMap<String, GlobalStat>> {
+        match self.global_stats {
+            Some(stats) if self.result == 1 => Ok(stats),
+            _ => Err(Error::Client(format!(
+                "request failed: {}",
+                self.error.unwrap_or_else(|| "no message".to_owned())
+            ))),
+        }
+    }
+}
+
+impl SteamClient {
+    /// Gets the aggregated [GlobalStats](GlobalStat) of the game by stat name
+    ///
+    /// Works without an API key. Only stats the developer has marked as
+    /// aggregated are available. When `start_date` and `end_date` (unix
+    /// timestamps) are provided the daily history of the stats is included.
+    pub async fn get_global_stats_for_game(
+        &self,
+        app_id: NonZeroU32,
+        names: &[&str],
+        start_date: Option<u32>,
+        end_date: Option<u32>,
+    ) -> Result<HashMap<String, GlobalStat>> {
+        if names.is_empty() {
+            return Err(Error::client("at least one stat name is required"));
+        }
+
+        let query = Params::new()
+            .add("appid", app_id)
+            .add("count", names.len())
+            .add_list("name", names)
+            .add_optional("startdate", start_date)
+            .add_optional("enddate", end_date)
+            .encode();
+        let uri = Uri::builder()
+            .scheme("https")
+            .authority(AUTHORITY)
+            .path_and_query(format!("{}?{}", PATH, query))
+            .build()?;
+
+        let response = self.client.get(uri).await?;
+        let body = response.into_body();
+        let parsed = from_slice::<Response>(&to_bytes(body).await?)?;
+
+        parsed.response.into_stats()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_history() {
+        let json = br#"{"response":{"result":1,"globalstats":{
+            "global.map.emp_isle":{"total":"5427381","history":[
+                {"date":1332460800,"total":"1234"},{"date":1332547200,"total":"4321"}
+            ]}}}}"#;
+        let stats = from_slice::<Response>(json).unwrap().response.into_stats().unwrap();
+        let stat = &stats["global.map.emp_isle"];
+        assert_eq!(stat.total, 5427381);
+        assert_eq!(stat.history.len(), 2);
+        assert_eq!(stat.history[1].total, 4321);
+    }
+
+    #[test]
+    fn unknown_stat() {
+        let json = br#"{"response":{"result":8,
+            "error":"Failed to get global stats for game, stat not found: not_a_stat"}}"#;
+        let error = from_slice::<Response>(json).unwrap().response.into_stats().unwrap_err();
+        assert!(matches!(
+            error,
+            Error::Client(message)
+                if message == "request failed: Failed to get global stats for game, stat not found: not_a_stat"
+        ));
+    }
+}
@@ -2,6 +2,7 @@ use std::num::NonZeroU32;
 
 use crate::client::SteamClient;
 use crate::error::Error;
+use crate::params::Params;
 use crate::utils::{ResponseWrapper, Result, AUTHORITY};
 use hyper::body::to_bytes;
 use hyper::Uri;
@@ -22,7 +23,9 @@ impl SteamClient {
     ///
     /// Works without an API key.
     pub async fn get_number_of_current_players(&self, game_id: NonZeroU32) -> Result<u32> {
-        let query = format!("appid={}", game_id);
+        let query = Params::new()
+            .add("appid", game_id)
+            .encode();
         let uri = Uri::builder()
             .scheme("https")
             .authority(AUTHORITY)
@@ -39,12 +42,11 @@ impl SteamClient {
             result,
         } = response.response;
 
-        if result == 1 && player_count.is_some() {
-            Ok(player_count.unwrap())
-        } else {
-            Err(Error::Client(
+        match player_count {
+            Some(count) if result == 1 => Ok(count),
+            _ => Err(Error::Client(
                 "request failed  check that game_id is valid".to_owned(),
-            ))
+            )),
         }
     }
 }
@@ -0,0 +1,164 @@
+use std::num::NonZeroU32;
+
+use crate::error::Error;
+use crate::params::Params;
+use crate::utils::{bool_from_int, check_ids, Result, AUTHORITY};
+use crate::{SteamClient, SteamID};
+
+use hyper::body::to_bytes;
+use hyper::Uri;
+
+use serde::Deserialize;
+use serde_json::from_slice;
+
+const PATH: &str = "/ISteamUserStats/GetPlayerAchievements/v1/";
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct PlayerAchievement {
+    /// Name of the achievement as unlocalized token
+    #[serde(rename = "apiname")]
+    pub api_name: String,
+    #[serde(deserialize_with = "bool_from_int")]
+    pub achieved: bool,
+    /// Unix timestamp of the unlock, 0 if the achievement isn't unlocked
+    #[serde(rename = "unlocktime")]
+    pub unlock_time: u32,
+    /// Localized name, only available when language is requested
+    pub name: Option<String>,
+    /// Localized description, only available when language is requested
+    pub description: Option<String>,
+}
+
+#[cfg(feature = "time")]
+impl PlayerAchievement {
+    /// [unlock_time](PlayerAchievement::unlock_time) as [OffsetDateTime](time::OffsetDateTime)
+    ///
+    /// `None` if the achievement isn't unlocked.
+    pub fn unlock_datetime(&self) -> Option<time::OffsetDateTime> {
+        match self.unlock_time {
+            0 => None,
+            secs => Some(crate::timestamp::from_unix(secs)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct PlayerAchievements {
+    pub id: SteamID,
+    pub game_name: String,
+    pub achievements: Vec<PlayerAchievement>,
+}
+
+#[derive(Deserialize)]
+struct PlayerStats {
+    success: bool,
+    error: Option<String>,
+    #[serde(rename = "steamID")]
+    id: Option<SteamID>,
+    #[serde(rename = "gameName")]
+    game_name: Option<String>,
+    #[serde(default)]
+    achievements: Vec<PlayerAchievement>,
+}
+
+#[derive(Deserialize)]
+struct Response {
+    playerstats: PlayerStats,
+}
+
+impl PlayerStats {
+    fn into_achievements(self) -> Result<PlayerAchievements> {
+        let PlayerStats {
+            success,
+            error,
+            id,
+            game_name,
+            achievements,
+        } = self;
+
+        match (success, id) {
+            (true, Some(id)) => Ok(PlayerAchievements {
+                id,
+                game_name: game_name.unwrap_or_default(),
+                achievements,
+            }),
+            _ => Err(match error.as_deref() {
+                Some("Profile is not public") => Error::PrivateProfile,
+                Some("Requested app has no stats") => Error::NoStats,
+                Some(message) => Error::Client(format!("request failed: {}", message)),
+                None => Error::client("request failed"),
+            }),
+        }
+    }
+}
+
+impl SteamClient {
+    /// Gets the [PlayerAchievements] of the user for the given game
+    ///
+    /// Requires an API key. Names and descriptions of the achievements are only
+    /// returned when `language` (for example `"english"`) is provided. Returns
+    /// [Error::PrivateProfile] if the users profile isn't public and
+    /// [Error::NoStats] if the game doesn't have achievements.
+    pub async fn get_player_achievements(
+        &self,
+        id: &SteamID,
+        app_id: NonZeroU32,
+        language: Option<&str>,
+    ) -> Result<PlayerAchievements> {
+        let api_key = self
+            .api_key
+            .as_ref()
+            .ok_or_else(|| Error::client("API key required"))?;
+        check_ids(&[*id])?;
+
+        let query = Params::new()
+            .add("key", api_key)
+            .add("steamid", id)
+            .add("appid", app_id)
+            .add_optional("l", language)
+            .encode();
+        let uri = Uri::builder()
+            .scheme("https")
+            .authority(AUTHORITY)
+            .path_and_query(format!("{}?{}", PATH, query))
+            .build()?;
+
+        let response = self.client.get(uri).await?;
+        let body = response.into_body();
+        let parsed = from_slice::<Response>(&to_bytes(body).await?)?;
+
+        parsed.playerstats.into_achievements()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use tokio_test::block_on;
+
+    #[test]
+    fn parse_errors() {
+        let private = br#"{"playerstats":{"error":"Profile is not public","success":false}}"#;
+        let no_stats = br#"{"playerstats":{"error":"Requested app has no stats","success":false}}"#;
+        let private = from_slice::<Response>(private).unwrap().playerstats;
+        let no_stats = from_slice::<Response>(no_stats).unwrap().playerstats;
+        assert!(matches!(private.into_achievements(), Err(Error::PrivateProfile)));
+        assert!(matches!(no_stats.into_achievements(), Err(Error::NoStats)));
+    }
+
+    #[test]
+    fn csgo_achievements() {
+        let client = SteamClient::with_api_key(&env::var("STEAM_API_KEY").unwrap());
+        let id = SteamID::from(76561198061271782);
+        let app_id = NonZeroU32::new(730).unwrap();
+        let achievements =
+            block_on(client.get_player_achievements(&id, app_id, Some("english"))).unwrap();
+        assert!(achievements
+            .achievements
+            .iter()
+            .all(|a| a.name.is_some() && a.achieved == (a.unlock_time != 0)));
+    }
+}
@@ -0,0 +1,168 @@
+use std::num::NonZeroU32;
+
+use crate::error::Error;
+use crate::params::Params;
+use crate::steam_user_stats::StatValue;
+use crate::utils::{bool_from_int, Result, AUTHORITY};
+use crate::SteamClient;
+
+use hyper::body::to_bytes;
+use hyper::Uri;
+
+use serde::Deserialize;
+use serde_json::from_slice;
+
+const PATH: &str = "/ISteamUserStats/GetSchemaForGame/v2/";
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct AchievementSchema {
+    /// Name of the achievement as unlocalized token
+    pub name: String,
+    #[serde(rename = "defaultvalue")]
+    pub default_value: StatValue,
+    #[serde(rename = "displayName")]
+    pub display_name: String,
+    /// Hidden achievements don't show their description until unlocked
+    #[serde(deserialize_with = "bool_from_int")]
+    pub hidden: bool,
+    /// Missing for some hidden achievements
+    pub description: Option<String>,
+    /// URL of the icon shown when the achievement is unlocked
+    pub icon: String,
+    /// URL of the icon shown when the achievement is locked
+    #[serde(rename = "icongray")]
+    pub icon_gray: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct StatSchema {
+    /// Name of the stat as unlocalized token
+    pub name: String,
+    #[serde(rename = "defaultvalue")]
+    pub default_value: StatValue,
+    #[serde(rename = "displayName")]
+    pub display_name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct GameSchema {
+    #[serde(rename = "gameName")]
+    pub game_name: String,
+    #[serde(rename = "gameVersion")]
+    pub game_version: String,
+    pub achievements: Vec<AchievementSchema>,
+    pub stats: Vec<StatSchema>,
+}
+
+#[derive(Deserialize, Default)]
+struct AvailableGameStats {
+    #[serde(default)]
+    achievements: Vec<AchievementSchema>,
+    #[serde(default)]
+    stats: Vec<StatSchema>,
+}
+
+#[derive(Deserialize)]
+struct Game {
+    #[serde(rename = "gameName")]
+    game_name: Option<String>,
+    #[serde(rename = "gameVersion")]
+    game_version: Option<String>,
+    #[serde(rename = "availableGameStats")]
+    #[serde(default)]
+    available_game_stats: AvailableGameStats,
+}
+
+#[derive(Deserialize)]
+struct Response {
+    game: Game,
+}
+
+impl Response {
+    fn into_schema(self) -> Result<GameSchema> {
+        let Game {
+            game_name,
+            game_version,
+            available_game_stats,
+        } = self.game;
+        let game_name = game_name.ok_or_else(|| Error::client("no game with provided app_id"))?;
+        Ok(GameSchema {
+            game_name,
+            game_version: game_version.unwrap_or_default(),
+            achievements: available_game_stats.achievements,
+            stats: available_game_stats.stats,
+        })
+    }
+}
+
+impl SteamClient {
+    /// Gets the [GameSchema] with the definitions of the achievements and stats of the game
+    ///
+    /// Requires an API key. Display names and descriptions are localized to the
+    /// given language (for example `"english"` or `"finnish"`), by default
+    /// english is used.
+    pub async fn get_schema_for_game(
+        &self,
+        app_id: NonZeroU32,
+        language: Option<&str>,
+    ) -> Result<GameSchema> {
+        let api_key = self
+            .api_key
+            .as_ref()
+            .ok_or_else(|| Error::client("API key required"))?;
+
+        let query = Params::new()
+            .add("key", api_key)
+            .add("appid", app_id)
+            .add_optional("l", language)
+            .encode();
+        let uri = Uri::builder()
+            .scheme("https")
+            .authority(AUTHORITY)
+            .path_and_query(format!("{}?{}", PATH, query))
+            .build()?;
+
+        let response = self.client.get(uri).await?;
+        let body = response.into_body();
+        let parsed = from_slice::<Response>(&to_bytes(body).await?)?;
+
+        parsed.into_schema()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use tokio_test::block_on;
+
+    #[test]
+    fn parse_schema() {
+        let json = br#"{"game":{"gameName":"Test","gameVersion":"12","availableGameStats":{
+            "stats":[{"name":"kills","defaultvalue":0,"displayName":"Kills"}],
+            "achievements":[
+                {"name":"WIN","defaultvalue":0,"displayName":"Winner","hidden":0,"description":"Win a game","icon":"a.jpg","icongray":"b.jpg"},
+                {"name":"SECRET","defaultvalue":0,"displayName":"Secret","hidden":1,"icon":"c.jpg","icongray":"d.jpg"}
+            ]}}}"#;
+        let schema = from_slice::<Response>(json).unwrap().into_schema().unwrap();
+        assert_eq!(schema.stats.len(), 1);
+        assert_eq!(schema.achievements.len(), 2);
+        assert!(schema.achievements[1].hidden);
+        assert_eq!(schema.achievements[1].description, None);
+
+        let empty = from_slice::<Response>(br#"{"game":{}}"#).unwrap();
+        assert!(empty.into_schema().is_err());
+    }
+
+    #[test]
+    fn csgo_schema() {
+        let client = SteamClient::with_api_key(&env::var("STEAM_API_KEY").unwrap());
+        let app_id = NonZeroU32::new(730).unwrap();
+        let schema = block_on(client.get_schema_for_game(app_id, Some("english"))).unwrap();
+        assert!(!schema.achievements.is_empty());
+        assert!(!schema.stats.is_empty());
+    }
+}
@@ -1,7 +1,9 @@
 use std::num::NonZeroU32;
 
 use crate::error::Error;
-use crate::utils::{Result, AUTHORITY};
+use crate::params::Params;
+use crate::steam_user_stats::StatValue;
+use crate::utils::{check_ids, Result, AUTHORITY};
 use crate::{SteamClient, SteamID};
 
 use hyper::body::to_bytes;
@@ -12,13 +14,15 @@ use serde_json::from_slice;
 
 const PATH: &str = "/ISteamUserStats/GetUserStatsForGame/v0002";
 
-#[derive(Deserialize, Debug)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
 pub struct Stat {
     pub name: String,
-    pub value: u32,
+    pub value: StatValue,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
 pub struct Achievement {
     pub name: String,
     pub achieved: u32,
@@ -29,7 +33,8 @@ struct Response {
     playerstats: PlayerStats,
 }
 
-#[derive(Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
 pub struct PlayerStats {
     #[serde(rename = "steamID")]
     pub id: SteamID,
@@ -52,8 +57,13 @@ impl SteamClient {
             .api_key
             .as_ref()
             .ok_or_else(|| Error::client("API key required"))?;
+        check_ids(&[*id])?;
 
-        let query = format!("key={}&steamid={}&appid={}", api_key, id, game_id);
+        let query = Params::new()
+            .add("key", api_key)
+            .add("steamid", id)
+            .add("appid", game_id)
+            .encode();
         let uri = Uri::builder()
             .scheme("https")
             .authority(AUTHORITY)
@@ -73,6 +83,29 @@ mod tests {
     use super::*;
     use std::env;
 
+    #[test]
+    fn parse_stat_values() {
+        let json = br#"{"playerstats":{"steamID":"76561198061271782","gameName":"Test",
+            "stats":[
+                {"name":"kills","value":1024},
+                {"name":"kd_ratio","value":1.25},
+                {"name":"balance","value":-30},
+                {"name":"distance","value":"12.5"}
+            ],
+            "achievements":[{"name":"WIN","achieved":1}]}}"#;
+        let stats = from_slice::<Response>(json).unwrap().playerstats.stats;
+        let values: Vec<StatValue> = stats.iter().map(|s| s.value).collect();
+        assert_eq!(
+            values,
+            [
+                StatValue::Int(1024),
+                StatValue::Float(1.25),
+                StatValue::Int(-30),
+                StatValue::Float(12.5)
+            ]
+        );
+    }
+
     #[test]
     fn fetch_stats() {
         let client = SteamClient::with_api_key(&env::var("STEAM_API_KEY").unwrap());
@@ -1,8 +1,18 @@
 //! Implementations for the ISteamUserStats interface
 
+mod achievement_report;
 mod get_global_achievement_percentages_for_app;
+mod get_global_stats_for_game;
 mod get_number_of_current_players;
+mod get_player_achievements;
+mod get_schema_for_game;
 mod get_user_stats_for_game;
+mod stat_value;
 
+pub use achievement_report::{AchievementReport, ReportedAchievement};
 pub use get_global_achievement_percentages_for_app::AchievementData;
+pub use get_global_stats_for_game::{GlobalStat, GlobalStatDay};
+pub use get_player_achievements::{PlayerAchievement, PlayerAchievements};
+pub use get_schema_for_game::{AchievementSchema, GameSchema, StatSchema};
 pub use get_user_stats_for_game::{Achievement, PlayerStats, Stat};
+pub use stat_value::StatValue;
@@ -0,0 +1,89 @@
+use std::fmt;
+
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer};
+
+/// Value of a stat
+///
+/// Games can publish both integer and float stats, and the API returns
+/// them either as numbers or as numbers in a string.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize), serde(untagged))]
+pub enum StatValue {
+    Int(i64),
+    Float(f64),
+}
+
+impl StatValue {
+    /// Returns the value as float, integers are converted
+    pub fn as_f64(&self) -> f64 {
+        match *self {
+            StatValue::Int(value) => value as f64,
+            StatValue::Float(value) => value,
+        }
+    }
+
+    /// Returns the value if it's an integer
+    pub fn as_i64(&self) -> Option<i64> {
+        match *self {
+            StatValue::Int(value) => Some(value),
+            StatValue::Float(_) => None,
+        }
+    }
+}
+
+impl Default for StatValue {
+    fn default() -> Self {
+        StatValue::Int(0)
+    }
+}
+
+impl fmt::Display for StatValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StatValue::Int(value) => write!(f, "{}", value),
+            StatValue::Float(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+struct StatValueVisitor;
+
+impl<'de> Visitor<'de> for StatValueVisitor {
+    type Value = StatValue;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a number or a number in a string")
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<StatValue, E> {
+        Ok(StatValue::Int(value))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<StatValue, E> {
+        Ok(i64::try_from(value)
+            .map(StatValue::Int)
+            .unwrap_or(StatValue::Float(value as f64)))
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<StatValue, E> {
+        Ok(StatValue::Float(value))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<StatValue, E> {
+        let value = value.trim();
+        if let Ok(int) = value.parse::<i64>() {
+            Ok(StatValue::Int(int))
+        } else if let Ok(float) = value.parse::<f64>() {
+            Ok(StatValue::Float(float))
+        } else {
+            Err(E::invalid_value(de::Unexpected::Str(value), &self))
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for StatValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(StatValueVisitor)
+    }
+}
@@ -0,0 +1,128 @@
+use std::sync::PoisonError;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::utils::{Result, AUTHORITY};
+use crate::SteamClient;
+
+use hyper::body::to_bytes;
+use hyper::Uri;
+
+use serde::Deserialize;
+use serde_json::from_slice;
+
+const PATH: &str = "/ISteamWebAPIUtil/GetServerInfo/v1/";
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct ServerInfo {
+    /// Unix timestamp of the Steam servers
+    #[serde(rename = "servertime")]
+    pub server_time: u32,
+    /// For example `Sat Oct 18 12:00:00 2026`, in Pacific time
+    #[serde(rename = "servertimestring")]
+    pub server_time_string: String,
+}
+
+#[cfg(feature = "time")]
+impl ServerInfo {
+    /// [server_time](ServerInfo::server_time) as [OffsetDateTime](time::OffsetDateTime)
+    pub fn server_time_datetime(&self) -> time::OffsetDateTime {
+        crate::timestamp::from_unix(self.server_time)
+    }
+}
+
+/// Local unix time in milliseconds
+fn local_millis() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_millis() as i64)
+}
+
+impl SteamClient {
+    /// Gets the [ServerInfo] with the current time of the Steam servers
+    ///
+    /// Works without an API key.
+    pub async fn get_server_info(&self) -> Result<ServerInfo> {
+        let uri = Uri::builder()
+            .scheme("https")
+            .authority(AUTHORITY)
+            .path_and_query(PATH)
+            .build()?;
+
+        let response = self.client.get(uri).await?;
+        let body = response.into_body();
+        Ok(from_slice::<ServerInfo>(&to_bytes(body).await?)?)
+    }
+
+    /// Measures how many seconds Steam's clock is ahead of the local clock
+    ///
+    /// Works without an API key. The offset is negative if the local clock
+    /// is ahead, it's stored on the client and used by
+    /// [steam_time](SteamClient::steam_time). Steam reports whole seconds so
+    /// the offset is accurate to about a second.
+    pub async fn measure_clock_offset(&self) -> Result<i64> {
+        let sent = local_millis();
+        let info = self.get_server_info().await?;
+        let received = local_millis();
+
+        // Assume the server time was taken halfway through the request. The
+        // server time is truncated to seconds, so on average it's half a
+        // second behind.
+        let local = (sent + received) / 2;
+        let server = i64::from(info.server_time) * 1000 + 500;
+        let offset = (server - local + 500).div_euclid(1000);
+
+        *self
+            .clock_offset
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = Some(offset);
+        Ok(offset)
+    }
+
+    /// Last offset measured with [measure_clock_offset](SteamClient::measure_clock_offset)
+    pub fn clock_offset(&self) -> Option<i64> {
+        *self
+            .clock_offset
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Current unix time of the Steam servers
+    ///
+    /// Local time corrected with the measured [clock_offset](SteamClient::clock_offset),
+    /// or just the local time if the offset hasn't been measured.
+    pub fn steam_time(&self) -> u32 {
+        let local = local_millis() / 1000;
+        let time = local + self.clock_offset().unwrap_or(0);
+        time.clamp(0, i64::from(u32::MAX)) as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio_test::block_on;
+
+    #[test]
+    fn parse_info() {
+        let json = br#"{"servertime":1760785200,"servertimestring":"Sat Oct 18 04:00:00 2026"}"#;
+        let info = from_slice::<ServerInfo>(json).unwrap();
+        assert_eq!(info.server_time, 1760785200);
+        assert_eq!(info.server_time_string, "Sat Oct 18 04:00:00 2026");
+    }
+
+    #[test]
+    fn unmeasured_offset() {
+        let client = SteamClient::new();
+        assert_eq!(client.clock_offset(), None);
+        let local = (local_millis() / 1000) as u32;
+        assert!(client.steam_time().abs_diff(local) <= 1);
+    }
+
+    #[test]
+    fn measure_offset() {
+        let client = SteamClient::new();
+        let offset = block_on(client.measure_clock_offset()).unwrap();
+        assert_eq!(client.clock_offset(), Some(offset));
+    }
+}
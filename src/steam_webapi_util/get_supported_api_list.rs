@@ -0,0 +1,107 @@
+use crate::params::Params;
+use crate::utils::{Result, AUTHORITY};
+use crate::SteamClient;
+
+use hyper::body::to_bytes;
+use hyper::Uri;
+
+use serde::Deserialize;
+use serde_json::from_slice;
+
+const PATH: &str = "/ISteamWebAPIUtil/GetSupportedAPIList/v1/";
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct ApiParameter {
+    pub name: String,
+    /// For example `uint32`, `string` or `{message}`
+    #[serde(rename = "type")]
+    pub parameter_type: String,
+    pub optional: bool,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct ApiMethod {
+    pub name: String,
+    pub version: u32,
+    /// `GET` or `POST`
+    #[serde(rename = "httpmethod")]
+    pub http_method: String,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub parameters: Vec<ApiParameter>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct ApiInterface {
+    pub name: String,
+    #[serde(default)]
+    pub methods: Vec<ApiMethod>,
+}
+
+#[derive(Deserialize)]
+struct ApiList {
+    interfaces: Vec<ApiInterface>,
+}
+
+#[derive(Deserialize)]
+struct Response {
+    apilist: ApiList,
+}
+
+impl SteamClient {
+    /// Gets the [ApiInterfaces](ApiInterface) of the Web API
+    ///
+    /// Works without an API key, but with a key the methods only available
+    /// to the key (for example publisher methods) are included.
+    pub async fn get_supported_api_list(&self) -> Result<Vec<ApiInterface>> {
+        let query = Params::new()
+            .add_optional("key", self.api_key.as_ref())
+            .encode();
+        let uri = Uri::builder()
+            .scheme("https")
+            .authority(AUTHORITY)
+            .path_and_query(format!("{}?{}", PATH, query))
+            .build()?;
+
+        let response = self.client.get(uri).await?;
+        let body = response.into_body();
+        let parsed = from_slice::<Response>(&to_bytes(body).await?)?;
+
+        Ok(parsed.apilist.interfaces)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio_test::block_on;
+
+    #[test]
+    fn parse_list() {
+        let json = br#"{"apilist":{"interfaces":[{"name":"ISteamUser","methods":[
+            {"name":"GetPlayerSummaries","version":2,"httpmethod":"GET","parameters":[
+                {"name":"key","type":"string","optional":false,"description":"access key"},
+                {"name":"steamids","type":"string","optional":false,"description":"Comma-delimited list of SteamIDs (max: 100)"}]},
+            {"name":"ResolveVanityURL","version":1,"httpmethod":"GET","parameters":[
+                {"name":"url_type","type":"int32","optional":true}]}]}]}}"#;
+        let interfaces = from_slice::<Response>(json).unwrap().apilist.interfaces;
+        let method = &interfaces[0].methods[1];
+        assert_eq!(method.name, "ResolveVanityURL");
+        assert_eq!(method.http_method, "GET");
+        assert!(method.parameters[0].optional);
+        assert_eq!(method.parameters[0].description, None);
+    }
+
+    #[test]
+    fn includes_itself() {
+        let client = SteamClient::new();
+        let interfaces = block_on(client.get_supported_api_list()).unwrap();
+        assert!(interfaces
+            .iter()
+            .any(|interface| interface.name == "ISteamWebAPIUtil"));
+    }
+}
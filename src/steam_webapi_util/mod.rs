@@ -0,0 +1,7 @@
+//! Implementations for the ISteamWebAPIUtil interface
+
+mod get_server_info;
+mod get_supported_api_list;
+
+pub use get_server_info::ServerInfo;
+pub use get_supported_api_list::{ApiInterface, ApiMethod, ApiParameter};
@@ -0,0 +1,282 @@
+use std::collections::HashMap;
+
+use crate::params::Params;
+use crate::utils::{check_status, option_from_empty_array, Result, STORE_AUTHORITY};
+use crate::SteamClient;
+
+use hyper::body::to_bytes;
+use hyper::Uri;
+
+use serde::Deserialize;
+use serde_aux::field_attributes::deserialize_number_from_string;
+use serde_json::from_slice;
+
+const PATH: &str = "/api/appdetails";
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct AppPrice {
+    /// For example `EUR`
+    pub currency: String,
+    /// Price without the discount
+    pub initial: u32,
+    #[serde(rename = "final")]
+    pub final_price: u32,
+    pub discount_percent: u32,
+    #[serde(default)]
+    pub initial_formatted: String,
+    #[serde(default)]
+    pub final_formatted: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct Requirements {
+    /// HTML
+    pub minimum: Option<String>,
+    /// HTML
+    pub recommended: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct Platforms {
+    pub windows: bool,
+    pub mac: bool,
+    pub linux: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct Metacritic {
+    pub score: u32,
+    pub url: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct Category {
+    pub id: u32,
+    pub description: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct Genre {
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub id: u32,
+    pub description: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct Screenshot {
+    pub id: u32,
+    pub path_thumbnail: String,
+    pub path_full: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct MovieFormats {
+    /// URL of the 480p version
+    #[serde(rename = "480")]
+    pub low: String,
+    /// URL of the highest quality version
+    pub max: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct Movie {
+    pub id: u32,
+    pub name: String,
+    pub thumbnail: String,
+    pub webm: Option<MovieFormats>,
+    pub mp4: Option<MovieFormats>,
+    #[serde(default)]
+    pub highlight: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct ReleaseDate {
+    pub coming_soon: bool,
+    /// Localized, for example `21 Aug, 2012`, or empty if not announced
+    pub date: String,
+}
+
+/// Store page of an app
+///
+/// Everything except the id is left empty if it's excluded by the filters.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct AppDetails {
+    #[serde(rename = "steam_appid")]
+    pub app_id: u32,
+    /// `game`, `dlc`, `demo`, `music`, ...
+    #[serde(rename = "type")]
+    #[serde(default)]
+    pub app_type: String,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default, deserialize_with = "deserialize_number_from_string")]
+    pub required_age: u32,
+    #[serde(default)]
+    pub is_free: bool,
+    /// HTML
+    #[serde(default)]
+    pub detailed_description: String,
+    /// HTML
+    #[serde(default)]
+    pub about_the_game: String,
+    #[serde(default)]
+    pub short_description: String,
+    /// HTML
+    #[serde(default)]
+    pub supported_languages: String,
+    #[serde(default)]
+    pub header_image: String,
+    pub website: Option<String>,
+    #[serde(default, deserialize_with = "option_from_empty_array")]
+    pub pc_requirements: Option<Requirements>,
+    #[serde(default, deserialize_with = "option_from_empty_array")]
+    pub mac_requirements: Option<Requirements>,
+    #[serde(default, deserialize_with = "option_from_empty_array")]
+    pub linux_requirements: Option<Requirements>,
+    #[serde(default)]
+    pub developers: Vec<String>,
+    #[serde(default)]
+    pub publishers: Vec<String>,
+    /// Missing for free and unreleased apps
+    pub price_overview: Option<AppPrice>,
+    /// Ids of the packages containing the app
+    #[serde(default)]
+    pub packages: Vec<u32>,
+    /// Ids of the DLCs of the app
+    #[serde(default)]
+    pub dlc: Vec<u32>,
+    pub platforms: Option<Platforms>,
+    pub metacritic: Option<Metacritic>,
+    #[serde(default)]
+    pub categories: Vec<Category>,
+    #[serde(default)]
+    pub genres: Vec<Genre>,
+    #[serde(default)]
+    pub screenshots: Vec<Screenshot>,
+    #[serde(default)]
+    pub movies: Vec<Movie>,
+    pub release_date: Option<ReleaseDate>,
+}
+
+#[derive(Deserialize)]
+struct AppResult {
+    success: bool,
+    #[serde(default, deserialize_with = "option_from_empty_array")]
+    data: Option<AppDetails>,
+}
+
+/// Results are keyed by the app id as a string
+type Response = HashMap<String, AppResult>;
+
+impl SteamClient {
+    /// Gets the [AppDetails] of the store page of the app
+    ///
+    /// Works without an API key. `country` (two letter country code) sets
+    /// the currency and availability, and `language` (for example
+    /// `"english"`) the language of the texts. `filters` limits the returned
+    /// fields to the given ones, for example `&["basic", "price_overview"]`.
+    /// Returns `None` if the app doesn't have a store page in the country.
+    pub async fn get_app_details(
+        &self,
+        app_id: u32,
+        country: Option<&str>,
+        language: Option<&str>,
+        filters: &[&str],
+    ) -> Result<Option<AppDetails>> {
+        let query = Params::new()
+            .add("appids", app_id)
+            .add_optional("cc", country)
+            .add_optional("l", language)
+            .add_joined("filters", filters)
+            .encode();
+        let uri = Uri::builder()
+            .scheme("https")
+            .authority(STORE_AUTHORITY)
+            .path_and_query(format!("{}?{}", PATH, query))
+            .build()?;
+
+        let response = self.client.get(uri).await?;
+        check_status(response.status())?;
+        let body = response.into_body();
+        let mut parsed = from_slice::<Response>(&to_bytes(body).await?)?;
+
+        Ok(parsed
+            .remove(&app_id.to_string())
+            .filter(|result| result.success)
+            .and_then(|result| result.data))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio_test::block_on;
+
+    #[test]
+    fn parse_details() {
+        let json = br#"{"730":{"success":true,"data":{"type":"game","name":"Counter-Strike 2",
+            "steam_appid":730,"required_age":"0","is_free":true,"dlc":[2678630],
+            "detailed_description":"<p>For over two decades</p>","about_the_game":"<p>For over</p>",
+            "short_description":"For over two decades","supported_languages":"English<strong>*</strong>",
+            "header_image":"https://shared.akamai.steamstatic.com/header.jpg","website":"http://counter-strike.net/cs2",
+            "pc_requirements":{"minimum":"<strong>Minimum:</strong>"},"mac_requirements":[],"linux_requirements":[],
+            "developers":["Valve"],"publishers":["Valve"],"packages":[298963],
+            "platforms":{"windows":true,"mac":false,"linux":true},
+            "metacritic":{"score":83,"url":"https://www.metacritic.com/game/pc/counter-strike-global-offensive"},
+            "categories":[{"id":1,"description":"Multi-player"}],
+            "genres":[{"id":"1","description":"Action"},{"id":"37","description":"Free To Play"}],
+            "screenshots":[{"id":0,"path_thumbnail":"https://a/ss_1.600x338.jpg","path_full":"https://a/ss_1.1920x1080.jpg"}],
+            "movies":[{"id":256972298,"name":"CS2 Launch","thumbnail":"https://a/movie.jpg",
+                "webm":{"480":"https://a/movie480.webm","max":"https://a/movie_max.webm"},
+                "mp4":{"480":"https://a/movie480.mp4","max":"https://a/movie_max.mp4"},"highlight":true}],
+            "recommendations":{"total":4000000},
+            "release_date":{"coming_soon":false,"date":"21 Aug, 2012"},
+            "background":"https://a/page_bg.jpg"}},
+            "10":{"success":false}}"#;
+        let mut response = from_slice::<Response>(json).unwrap();
+        assert!(!response["10"].success);
+        let details = response.remove("730").unwrap().data.unwrap();
+        assert_eq!(details.app_id, 730);
+        assert_eq!(details.required_age, 0);
+        assert_eq!(details.price_overview, None);
+        assert!(details.pc_requirements.is_some());
+        assert_eq!(details.mac_requirements, None);
+        assert_eq!(details.genres[1].id, 37);
+        assert_eq!(details.platforms.map(|platforms| platforms.mac), Some(false));
+        assert_eq!(details.movies[0].webm.as_ref().unwrap().low, "https://a/movie480.webm");
+        assert_eq!(details.release_date.unwrap().date, "21 Aug, 2012");
+    }
+
+    #[test]
+    fn parse_price_filter() {
+        let json = br#"{"440":{"success":true,"data":[]},
+            "570":{"success":true,"data":{"steam_appid":570,"price_overview":{"currency":"EUR",
+                "initial":1999,"final":999,"discount_percent":50,"initial_formatted":"19,99\u20ac",
+                "final_formatted":"9,99\u20ac"}}}}"#;
+        let mut response = from_slice::<Response>(json).unwrap();
+        assert_eq!(response.remove("440").unwrap().data, None);
+        let price = response.remove("570").unwrap().data.unwrap().price_overview.unwrap();
+        assert_eq!(price.final_price, 999);
+        assert_eq!(price.discount_percent, 50);
+    }
+
+    #[test]
+    fn csgo_details() {
+        let client = SteamClient::new();
+        let details = block_on(client.get_app_details(730, Some("us"), Some("english"), &[]))
+            .unwrap()
+            .unwrap();
+        assert_eq!(details.app_id, 730);
+    }
+}
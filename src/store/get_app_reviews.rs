@@ -0,0 +1,389 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::error::Error;
+use crate::params::Params;
+use crate::utils::{check_status, u64_from_str, Result, STORE_AUTHORITY};
+use crate::{SteamClient, SteamID};
+
+use futures::stream::{self, Stream, TryStreamExt};
+use hyper::body::to_bytes;
+use hyper::Uri;
+
+use serde::Deserialize;
+use serde_aux::field_attributes::deserialize_number_from_string;
+use serde_json::from_slice;
+
+const PATH: &str = "/appreviews/";
+
+/// Order of the reviews
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ReviewFilter {
+    /// Most helpful first, the only order that uses [day_range](ReviewQuery::day_range)
+    #[default]
+    Helpful,
+    /// Newest first
+    Recent,
+    /// Last updated first
+    Updated,
+}
+
+impl fmt::Display for ReviewFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let repr = match self {
+            ReviewFilter::Helpful => "all",
+            ReviewFilter::Recent => "recent",
+            ReviewFilter::Updated => "updated",
+        };
+        write!(f, "{}", repr)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ReviewType {
+    #[default]
+    All,
+    Positive,
+    Negative,
+}
+
+impl fmt::Display for ReviewType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let repr = match self {
+            ReviewType::All => "all",
+            ReviewType::Positive => "positive",
+            ReviewType::Negative => "negative",
+        };
+        write!(f, "{}", repr)
+    }
+}
+
+/// Where the reviewers got the app
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PurchaseType {
+    All,
+    /// Bought from Steam, the default of the store
+    #[default]
+    Steam,
+    /// Activated with a key
+    NonSteam,
+}
+
+impl fmt::Display for PurchaseType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let repr = match self {
+            PurchaseType::All => "all",
+            PurchaseType::Steam => "steam",
+            PurchaseType::NonSteam => "non_steam_purchase",
+        };
+        write!(f, "{}", repr)
+    }
+}
+
+/// Builder for review queries
+///
+/// Used with [get_app_reviews](SteamClient::get_app_reviews) and
+/// [get_app_reviews_stream](SteamClient::get_app_reviews_stream).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReviewQuery {
+    filter: ReviewFilter,
+    language: Option<String>,
+    day_range: Option<u32>,
+    review_type: ReviewType,
+    purchase_type: PurchaseType,
+    per_page: u32,
+}
+
+impl Default for ReviewQuery {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ReviewQuery {
+    /// Query for the most helpful reviews in all languages by users who bought the app on Steam
+    pub fn new() -> Self {
+        ReviewQuery {
+            filter: ReviewFilter::default(),
+            language: None,
+            day_range: None,
+            review_type: ReviewType::default(),
+            purchase_type: PurchaseType::default(),
+            per_page: 100,
+        }
+    }
+
+    pub fn filter(mut self, filter: ReviewFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Only reviews in the language, for example `"english"`
+    pub fn language(mut self, language: &str) -> Self {
+        self.language = Some(language.to_owned());
+        self
+    }
+
+    /// Only reviews from the last `days` days, max 365
+    pub fn day_range(mut self, days: u32) -> Self {
+        self.day_range = Some(days);
+        self
+    }
+
+    pub fn review_type(mut self, review_type: ReviewType) -> Self {
+        self.review_type = review_type;
+        self
+    }
+
+    pub fn purchase_type(mut self, purchase_type: PurchaseType) -> Self {
+        self.purchase_type = purchase_type;
+        self
+    }
+
+    /// Reviews per request, default and max 100
+    pub fn per_page(mut self, per_page: u32) -> Self {
+        self.per_page = per_page;
+        self
+    }
+
+    fn to_params(&self, cursor: &str) -> Params {
+        Params::new()
+            .add("json", 1)
+            .add("cursor", cursor)
+            .add("filter", self.filter)
+            .add("language", self.language.as_deref().unwrap_or("all"))
+            .add_optional("day_range", self.day_range)
+            .add("review_type", self.review_type)
+            .add("purchase_type", self.purchase_type)
+            .add("num_per_page", self.per_page)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct ReviewSummary {
+    /// Number of reviews on the page
+    pub num_reviews: u32,
+    /// Totals are only returned for the first page
+    pub review_score: Option<u32>,
+    /// For example `Very Positive`
+    pub review_score_desc: Option<String>,
+    pub total_positive: Option<u32>,
+    pub total_negative: Option<u32>,
+    pub total_reviews: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct ReviewAuthor {
+    #[serde(rename = "steamid")]
+    pub id: SteamID,
+    #[serde(default)]
+    pub num_games_owned: u32,
+    #[serde(default)]
+    pub num_reviews: u32,
+    /// Minutes played
+    #[serde(default)]
+    pub playtime_forever: u32,
+    /// Minutes played in the last two weeks
+    #[serde(default)]
+    pub playtime_last_two_weeks: u32,
+    /// Minutes played when the review was written
+    pub playtime_at_review: Option<u32>,
+    /// Unix timestamp
+    #[serde(default)]
+    pub last_played: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct Review {
+    #[serde(rename = "recommendationid")]
+    #[serde(deserialize_with = "u64_from_str")]
+    #[cfg_attr(feature = "serde-serialize", serde(serialize_with = "crate::utils::u64_to_str"))]
+    pub id: u64,
+    pub author: ReviewAuthor,
+    pub language: String,
+    pub review: String,
+    /// Unix timestamp
+    pub timestamp_created: u32,
+    /// Unix timestamp
+    pub timestamp_updated: u32,
+    /// Does the author recommend the app
+    pub voted_up: bool,
+    pub votes_up: u32,
+    pub votes_funny: u32,
+    /// Helpfulness between 0 and 1
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub weighted_vote_score: f64,
+    #[serde(default)]
+    pub comment_count: u32,
+    pub steam_purchase: bool,
+    pub received_for_free: bool,
+    pub written_during_early_access: bool,
+}
+
+#[cfg(feature = "time")]
+impl Review {
+    /// [timestamp_created](Review::timestamp_created) as [OffsetDateTime](time::OffsetDateTime)
+    pub fn created_datetime(&self) -> time::OffsetDateTime {
+        crate::timestamp::from_unix(self.timestamp_created)
+    }
+
+    /// [timestamp_updated](Review::timestamp_updated) as [OffsetDateTime](time::OffsetDateTime)
+    pub fn updated_datetime(&self) -> time::OffsetDateTime {
+        crate::timestamp::from_unix(self.timestamp_updated)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct ReviewPage {
+    pub summary: ReviewSummary,
+    pub reviews: Vec<Review>,
+    /// Cursor of the next page, `None` after the last page
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct Response {
+    success: u32,
+    query_summary: Option<ReviewSummary>,
+    #[serde(default)]
+    reviews: Vec<Review>,
+    cursor: Option<String>,
+}
+
+impl Response {
+    fn into_page(self, cursor: &str) -> Result<ReviewPage> {
+        let summary = match self.query_summary {
+            Some(summary) if self.success == 1 => summary,
+            _ => return Err(Error::client("request failed")),
+        };
+        // The last page returns its own cursor again
+        let next_cursor = match self.cursor {
+            Some(next) if !self.reviews.is_empty() && next != cursor => Some(next),
+            _ => None,
+        };
+        Ok(ReviewPage {
+            summary,
+            reviews: self.reviews,
+            next_cursor,
+        })
+    }
+}
+
+impl SteamClient {
+    /// Gets a page of the [Reviews](Review) of the app matching the [ReviewQuery]
+    ///
+    /// Works without an API key. Pass `"*"` as the cursor for the first page,
+    /// which also contains the review score and totals in its [ReviewSummary],
+    /// and [next_cursor](ReviewPage::next_cursor) for the following ones, or use
+    /// [get_app_reviews_stream](SteamClient::get_app_reviews_stream).
+    pub async fn get_app_reviews(
+        &self,
+        app_id: u32,
+        query: &ReviewQuery,
+        cursor: &str,
+    ) -> Result<ReviewPage> {
+        let query = query.to_params(cursor).encode();
+        let uri = Uri::builder()
+            .scheme("https")
+            .authority(STORE_AUTHORITY)
+            .path_and_query(format!("{}{}?{}", PATH, app_id, query))
+            .build()?;
+
+        let response = self.client.get(uri).await?;
+        check_status(response.status())?;
+        let body = response.into_body();
+        let parsed = from_slice::<Response>(&to_bytes(body).await?)?;
+
+        parsed.into_page(cursor)
+    }
+
+    /// Returns a stream of all the [Reviews](Review) of the app matching the [ReviewQuery]
+    ///
+    /// Works without an API key. Pages are fetched as the stream is consumed,
+    /// following the cursors until the reviews are exhausted. The cursors of
+    /// [Helpful](ReviewFilter::Helpful) can cycle, so the stream also ends
+    /// when a cursor comes back.
+    pub fn get_app_reviews_stream(
+        &self,
+        app_id: u32,
+        query: ReviewQuery,
+    ) -> impl Stream<Item = Result<Review>> + '_ {
+        stream::try_unfold(
+            (query, HashSet::new(), Some("*".to_owned())),
+            move |(query, mut seen, cursor)| async move {
+                let cursor = match cursor {
+                    Some(cursor) => cursor,
+                    None => return Ok(None),
+                };
+                let page = self.get_app_reviews(app_id, &query, &cursor).await?;
+                seen.insert(cursor);
+                let next_cursor = page.next_cursor.filter(|next| !seen.contains(next));
+                Ok::<_, Error>(Some((page.reviews, (query, seen, next_cursor))))
+            },
+        )
+        .map_ok(|reviews| stream::iter(reviews.into_iter().map(Ok)))
+        .try_flatten()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+    use tokio_test::block_on;
+
+    #[test]
+    fn query_string() {
+        let query = ReviewQuery::new()
+            .filter(ReviewFilter::Recent)
+            .review_type(ReviewType::Negative)
+            .purchase_type(PurchaseType::NonSteam)
+            .day_range(30);
+        assert_eq!(
+            query.to_params("AoJ4+sk=").encode(),
+            "json=1&cursor=AoJ4%2Bsk%3D&filter=recent&language=all&day_range=30\
+             &review_type=negative&purchase_type=non_steam_purchase&num_per_page=100"
+        );
+    }
+
+    #[test]
+    fn parse_pages() {
+        let json = br#"{"success":1,"query_summary":{"num_reviews":1,"review_score":8,
+            "review_score_desc":"Very Positive","total_positive":7000,"total_negative":800,"total_reviews":7800},
+            "reviews":[{"recommendationid":"151612270","author":{"steamid":"76561198061271782",
+                "num_games_owned":120,"num_reviews":5,"playtime_forever":3000,"playtime_last_two_weeks":60,
+                "playtime_at_review":2500,"last_played":1700000000},"language":"english","review":"Great",
+                "timestamp_created":1699000000,"timestamp_updated":1699000100,"voted_up":true,"votes_up":10,
+                "votes_funny":1,"weighted_vote_score":"0.61","comment_count":0,"steam_purchase":true,
+                "received_for_free":false,"written_during_early_access":false,"primarily_steam_deck":false}],
+            "cursor":"AoJ4+sk="}"#;
+        let page = from_slice::<Response>(json).unwrap().into_page("*").unwrap();
+        assert_eq!(page.summary.total_reviews, Some(7800));
+        let review = &page.reviews[0];
+        assert_eq!(review.id, 151612270);
+        assert_eq!(review.author.id, SteamID::from(76561198061271782));
+        assert_eq!(review.author.playtime_at_review, Some(2500));
+        assert_eq!(review.weighted_vote_score, 0.61);
+        assert_eq!(page.next_cursor.as_deref(), Some("AoJ4+sk="));
+
+        let json = br#"{"success":1,"query_summary":{"num_reviews":0},"reviews":[],"cursor":"AoJ4+sk="}"#;
+        let page = from_slice::<Response>(json).unwrap().into_page("AoJ4+sk=").unwrap();
+        assert_eq!(page.summary.total_reviews, None);
+        assert_eq!(page.next_cursor, None);
+
+        let json = br#"{"success":2}"#;
+        assert!(from_slice::<Response>(json).unwrap().into_page("*").is_err());
+    }
+
+    #[test]
+    fn csgo_recent_reviews() {
+        let client = SteamClient::new();
+        let query = ReviewQuery::new().filter(ReviewFilter::Recent).per_page(20);
+        let reviews: Vec<_> = block_on(client.get_app_reviews_stream(730, query).take(30).collect());
+        assert_eq!(reviews.len(), 30);
+    }
+}
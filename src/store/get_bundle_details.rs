@@ -0,0 +1,107 @@
+use crate::params::Params;
+use crate::utils::{check_status, Result, STORE_AUTHORITY};
+use crate::SteamClient;
+
+use hyper::body::to_bytes;
+use hyper::Uri;
+
+use serde::Deserialize;
+use serde_json::from_slice;
+
+const PATH: &str = "/actions/ajaxresolvebundles";
+
+/// Store page of a bundle
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct BundleDetails {
+    #[serde(rename = "bundleid")]
+    pub id: u32,
+    pub name: String,
+    #[serde(default)]
+    pub header_image_url: String,
+    /// Apps included in the bundle
+    #[serde(rename = "appids")]
+    #[serde(default)]
+    pub app_ids: Vec<u32>,
+    /// Packages included in the bundle
+    #[serde(rename = "packageids")]
+    #[serde(default)]
+    pub package_ids: Vec<u32>,
+    /// Price of the bundle without the discount
+    #[serde(default)]
+    pub initial_price: u32,
+    #[serde(default)]
+    pub final_price: u32,
+    #[serde(default)]
+    pub discount_percent: u32,
+    /// Discount for buying the items as a bundle, included in
+    /// [discount_percent](BundleDetails::discount_percent)
+    #[serde(default)]
+    pub bundle_base_discount: u32,
+    #[serde(default)]
+    pub formatted_orig_price: String,
+    #[serde(default)]
+    pub formatted_final_price: String,
+}
+
+impl SteamClient {
+    /// Gets the [BundleDetails] of the bundles with a single request
+    ///
+    /// Works without an API key. `country` (two letter country code) sets
+    /// the currency and `language` (for example `"english"`) the language
+    /// of the names. Bundles that don't exist are left out.
+    pub async fn get_bundle_details(
+        &self,
+        bundle_ids: &[u32],
+        country: Option<&str>,
+        language: Option<&str>,
+    ) -> Result<Vec<BundleDetails>> {
+        if bundle_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let query = Params::new()
+            .add_joined("bundleids", bundle_ids)
+            .add_optional("cc", country)
+            .add_optional("l", language)
+            .encode();
+        let uri = Uri::builder()
+            .scheme("https")
+            .authority(STORE_AUTHORITY)
+            .path_and_query(format!("{}?{}", PATH, query))
+            .build()?;
+
+        let response = self.client.get(uri).await?;
+        check_status(response.status())?;
+        let body = response.into_body();
+        Ok(from_slice::<Vec<BundleDetails>>(&to_bytes(body).await?)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio_test::block_on;
+
+    #[test]
+    fn parse_bundles() {
+        let json = br#"[{"bundleid":232,"name":"Valve Complete Pack","header_image_url":"https://a/header.jpg",
+            "main_capsule":"https://a/capsule.jpg","appids":[10,20,30,40,50,60,70,130,220,240,280,300,320,340,360,380,400,420,440,500,550,620],
+            "packageids":[7,29],"creator_clan_ids":[],"initial_price":21950,"final_price":4990,
+            "discount_percent":77,"bundle_base_discount":77,"formatted_orig_price":"$219.50",
+            "formatted_final_price":"$49.90","is_nonrefundable":false}]"#;
+        let bundles = from_slice::<Vec<BundleDetails>>(json).unwrap();
+        let bundle = &bundles[0];
+        assert_eq!(bundle.id, 232);
+        assert!(bundle.app_ids.contains(&440));
+        assert_eq!(bundle.package_ids, [7, 29]);
+        assert_eq!(bundle.final_price, 4990);
+    }
+
+    #[test]
+    fn valve_complete_pack() {
+        let client = SteamClient::new();
+        let bundles = block_on(client.get_bundle_details(&[232], Some("us"), None)).unwrap();
+        assert_eq!(bundles[0].id, 232);
+    }
+}
@@ -0,0 +1,150 @@
+use std::collections::HashMap;
+
+use crate::params::Params;
+use crate::store::{Platforms, ReleaseDate};
+use crate::utils::{check_status, option_from_empty_array, Result, STORE_AUTHORITY};
+use crate::SteamClient;
+
+use hyper::body::to_bytes;
+use hyper::Uri;
+
+use serde::Deserialize;
+use serde_json::from_slice;
+
+const PATH: &str = "/api/packagedetails";
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct PackagePrice {
+    /// For example `EUR`
+    pub currency: String,
+    /// Price without the discount
+    pub initial: u32,
+    #[serde(rename = "final")]
+    pub final_price: u32,
+    pub discount_percent: u32,
+    /// Sum of the prices of the apps bought separately
+    #[serde(default)]
+    pub individual: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct PackageApp {
+    pub id: u32,
+    pub name: String,
+}
+
+/// Store page of a package (also called subscription)
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct PackageDetails {
+    /// Not part of the response data, filled from the requested id
+    #[serde(default)]
+    pub id: u32,
+    pub name: String,
+    #[serde(default)]
+    pub header_image: String,
+    #[serde(default)]
+    pub small_logo: String,
+    /// Apps included in the package
+    #[serde(default)]
+    pub apps: Vec<PackageApp>,
+    /// Missing for free packages
+    pub price: Option<PackagePrice>,
+    pub platforms: Option<Platforms>,
+    pub release_date: Option<ReleaseDate>,
+}
+
+#[derive(Deserialize)]
+struct PackageResult {
+    success: bool,
+    #[serde(default, deserialize_with = "option_from_empty_array")]
+    data: Option<PackageDetails>,
+}
+
+/// Results are keyed by the package id as a string
+type Response = HashMap<String, PackageResult>;
+
+fn into_packages(response: Response) -> Vec<PackageDetails> {
+    let mut packages: Vec<PackageDetails> = response
+        .into_iter()
+        .filter_map(|(id, result)| {
+            let mut package = result.data.filter(|_| result.success)?;
+            package.id = id.parse().ok()?;
+            Some(package)
+        })
+        .collect();
+    packages.sort_by_key(|package| package.id);
+    packages
+}
+
+impl SteamClient {
+    /// Gets the [PackageDetails] of the packages with a single request
+    ///
+    /// Works without an API key. `country` (two letter country code) sets
+    /// the currency and availability, and `language` (for example
+    /// `"english"`) the language of the names. Packages that don't exist or
+    /// aren't available in the country are left out, the rest are sorted by id.
+    pub async fn get_package_details(
+        &self,
+        package_ids: &[u32],
+        country: Option<&str>,
+        language: Option<&str>,
+    ) -> Result<Vec<PackageDetails>> {
+        if package_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let query = Params::new()
+            .add_joined("packageids", package_ids)
+            .add_optional("cc", country)
+            .add_optional("l", language)
+            .encode();
+        let uri = Uri::builder()
+            .scheme("https")
+            .authority(STORE_AUTHORITY)
+            .path_and_query(format!("{}?{}", PATH, query))
+            .build()?;
+
+        let response = self.client.get(uri).await?;
+        check_status(response.status())?;
+        let body = response.into_body();
+        let parsed = from_slice::<Response>(&to_bytes(body).await?)?;
+
+        Ok(into_packages(parsed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio_test::block_on;
+
+    #[test]
+    fn parse_packages() {
+        let json = br#"{
+            "469":{"success":true,"data":{"name":"The Orange Box","page_content":"",
+                "page_image":"https://a/page.jpg","header_image":"https://a/header.jpg","small_logo":"https://a/logo.jpg",
+                "apps":[{"id":220,"name":"Half-Life 2"},{"id":440,"name":"Team Fortress 2"}],
+                "price":{"currency":"USD","initial":1999,"final":999,"discount_percent":50,"individual":4497},
+                "platforms":{"windows":true,"mac":false,"linux":false},"controller":{"full_gamepad":false},
+                "release_date":{"coming_soon":false,"date":"10 Oct, 2007"}}},
+            "1":{"success":false},
+            "0":{"success":true,"data":[]}}"#;
+        let packages = into_packages(from_slice::<Response>(json).unwrap());
+        assert_eq!(packages.len(), 1);
+        let package = &packages[0];
+        assert_eq!(package.id, 469);
+        assert_eq!(package.apps[1].id, 440);
+        let price = package.price.as_ref().unwrap();
+        assert_eq!((price.final_price, price.individual), (999, 4497));
+    }
+
+    #[test]
+    fn orange_box() {
+        let client = SteamClient::new();
+        let packages = block_on(client.get_package_details(&[469], Some("us"), None)).unwrap();
+        assert!(packages[0].apps.iter().any(|app| app.id == 440));
+    }
+}
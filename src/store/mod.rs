@@ -0,0 +1,21 @@
+//! Steam Store
+//!
+//! The store API isn't part of the Web API, it doesn't use API keys and is
+//! rate limited to roughly 200 requests in 5 minutes. Prices are in
+//! hundredths of the currency unit.
+
+mod get_app_details;
+mod get_app_reviews;
+mod get_bundle_details;
+mod get_package_details;
+
+pub use get_app_details::{
+    AppDetails, AppPrice, Category, Genre, Metacritic, Movie, MovieFormats, Platforms,
+    ReleaseDate, Requirements, Screenshot,
+};
+pub use get_app_reviews::{
+    PurchaseType, Review, ReviewAuthor, ReviewFilter, ReviewPage, ReviewQuery, ReviewSummary,
+    ReviewType,
+};
+pub use get_bundle_details::BundleDetails;
+pub use get_package_details::{PackageApp, PackageDetails, PackagePrice};
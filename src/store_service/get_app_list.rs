@@ -0,0 +1,241 @@
+use crate::error::Error;
+use crate::params::Params;
+use crate::utils::{ResponseWrapper, Result, AUTHORITY};
+use crate::SteamClient;
+
+use futures::stream::{self, Stream, TryStreamExt};
+use hyper::body::to_bytes;
+use hyper::Uri;
+
+use serde::Deserialize;
+use serde_json::from_slice;
+
+const PATH: &str = "/IStoreService/GetAppList/v1/";
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct StoreApp {
+    #[serde(rename = "appid")]
+    pub id: u32,
+    pub name: String,
+    /// Unix timestamp of the last change to the store page
+    pub last_modified: u32,
+    /// Changes when the price of the app changes
+    pub price_change_number: u32,
+}
+
+#[cfg(feature = "time")]
+impl StoreApp {
+    /// [last_modified](StoreApp::last_modified) as [OffsetDateTime](time::OffsetDateTime)
+    pub fn last_modified_datetime(&self) -> time::OffsetDateTime {
+        crate::timestamp::from_unix(self.last_modified)
+    }
+}
+
+/// Builder for store app list queries
+///
+/// Used with [get_store_app_list](SteamClient::get_store_app_list) and
+/// [get_store_app_list_stream](SteamClient::get_store_app_list_stream).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AppListQuery {
+    if_modified_since: Option<u32>,
+    include_games: bool,
+    include_dlc: bool,
+    include_software: bool,
+    include_videos: bool,
+    include_hardware: bool,
+    per_page: u32,
+}
+
+impl Default for AppListQuery {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AppListQuery {
+    /// Query for all the games
+    pub fn new() -> Self {
+        AppListQuery {
+            if_modified_since: None,
+            include_games: true,
+            include_dlc: false,
+            include_software: false,
+            include_videos: false,
+            include_hardware: false,
+            per_page: 10_000,
+        }
+    }
+
+    /// Only apps changed after the unix timestamp, for incremental updates
+    pub fn if_modified_since(mut self, timestamp: u32) -> Self {
+        self.if_modified_since = Some(timestamp);
+        self
+    }
+
+    /// Default true
+    pub fn include_games(mut self, value: bool) -> Self {
+        self.include_games = value;
+        self
+    }
+
+    pub fn include_dlc(mut self, value: bool) -> Self {
+        self.include_dlc = value;
+        self
+    }
+
+    pub fn include_software(mut self, value: bool) -> Self {
+        self.include_software = value;
+        self
+    }
+
+    pub fn include_videos(mut self, value: bool) -> Self {
+        self.include_videos = value;
+        self
+    }
+
+    pub fn include_hardware(mut self, value: bool) -> Self {
+        self.include_hardware = value;
+        self
+    }
+
+    /// Apps per request, default 10 000 and max 50 000
+    pub fn per_page(mut self, per_page: u32) -> Self {
+        self.per_page = per_page;
+        self
+    }
+
+    fn to_params(&self, api_key: &str, last_app_id: Option<u32>) -> Params {
+        Params::new()
+            .add("key", api_key)
+            .add_optional("if_modified_since", self.if_modified_since)
+            .add("include_games", self.include_games)
+            .add("include_dlc", self.include_dlc)
+            .add("include_software", self.include_software)
+            .add("include_videos", self.include_videos)
+            .add("include_hardware", self.include_hardware)
+            .add_optional("last_appid", last_app_id)
+            .add("max_results", self.per_page)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize))]
+pub struct AppListPage {
+    #[serde(default)]
+    pub apps: Vec<StoreApp>,
+    #[serde(default)]
+    pub have_more_results: bool,
+    /// Id to continue from, only returned if there are more results
+    #[serde(rename = "last_appid")]
+    pub last_app_id: Option<u32>,
+}
+
+type Response = ResponseWrapper<AppListPage>;
+
+impl SteamClient {
+    /// Gets a page of the [StoreApps](StoreApp) matching the [AppListQuery], ordered by id
+    ///
+    /// Requires an API key. Pass `None` as `last_app_id` for the first page
+    /// and [last_app_id](AppListPage::last_app_id) for the following ones, or
+    /// use [get_store_app_list_stream](SteamClient::get_store_app_list_stream).
+    pub async fn get_store_app_list(
+        &self,
+        query: &AppListQuery,
+        last_app_id: Option<u32>,
+    ) -> Result<AppListPage> {
+        let api_key = self
+            .api_key
+            .as_ref()
+            .ok_or_else(|| Error::client("API key required"))?;
+
+        let query = query.to_params(api_key, last_app_id).encode();
+        let uri = Uri::builder()
+            .scheme("https")
+            .authority(AUTHORITY)
+            .path_and_query(format!("{}?{}", PATH, query))
+            .build()?;
+
+        let response = self.client.get(uri).await?;
+        let body = response.into_body();
+        let parsed = from_slice::<Response>(&to_bytes(body).await?)?;
+
+        Ok(parsed.response)
+    }
+
+    /// Returns a stream of all the [StoreApps](StoreApp) matching the [AppListQuery]
+    ///
+    /// Requires an API key. Pages are fetched as the stream is consumed.
+    /// Unlike [get_app_list](SteamClient::get_app_list) the list doesn't have
+    /// to be downloaded at once, and with
+    /// [if_modified_since](AppListQuery::if_modified_since) only the changes
+    /// since the last sync are fetched.
+    pub fn get_store_app_list_stream(
+        &self,
+        query: AppListQuery,
+    ) -> impl Stream<Item = Result<StoreApp>> + '_ {
+        stream::try_unfold(
+            (query, Some(None)),
+            move |(query, state): (AppListQuery, Option<Option<u32>>)| async move {
+                let last_app_id = match state {
+                    Some(last_app_id) => last_app_id,
+                    None => return Ok(None),
+                };
+                let page = self.get_store_app_list(&query, last_app_id).await?;
+                let next = match page.last_app_id {
+                    Some(last) if page.have_more_results => Some(Some(last)),
+                    _ => None,
+                };
+                Ok::<_, Error>(Some((page.apps, (query, next))))
+            },
+        )
+        .map_ok(|apps| stream::iter(apps.into_iter().map(Ok)))
+        .try_flatten()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+    use std::env;
+    use tokio_test::block_on;
+
+    #[test]
+    fn query_string() {
+        let query = AppListQuery::new()
+            .include_dlc(true)
+            .if_modified_since(1700000000)
+            .per_page(500);
+        assert_eq!(
+            query.to_params("ABC", Some(730)).encode(),
+            "key=ABC&if_modified_since=1700000000&include_games=true&include_dlc=true\
+             &include_software=false&include_videos=false&include_hardware=false\
+             &last_appid=730&max_results=500"
+        );
+    }
+
+    #[test]
+    fn parse_page() {
+        let json = br#"{"response":{"apps":[
+            {"appid":10,"name":"Counter-Strike","last_modified":1666823513,"price_change_number":21319021},
+            {"appid":20,"name":"Team Fortress Classic","last_modified":1579634708,"price_change_number":21319021}],
+            "have_more_results":true,"last_appid":20}}"#;
+        let page = from_slice::<Response>(json).unwrap().response;
+        assert!(page.have_more_results);
+        assert_eq!(page.last_app_id, Some(20));
+        assert_eq!(page.apps[1].name, "Team Fortress Classic");
+
+        let json = br#"{"response":{}}"#;
+        let page = from_slice::<Response>(json).unwrap().response;
+        assert!(page.apps.is_empty() && !page.have_more_results);
+    }
+
+    #[test]
+    fn first_games() {
+        let client = SteamClient::with_api_key(&env::var("STEAM_API_KEY").unwrap());
+        let query = AppListQuery::new().per_page(100);
+        let apps: Vec<_> = block_on(client.get_store_app_list_stream(query).take(150).collect());
+        assert_eq!(apps.len(), 150);
+    }
+}
@@ -0,0 +1,5 @@
+//! Implementations for the IStoreService interface
+
+mod get_app_list;
+
+pub use get_app_list::{AppListPage, AppListQuery, StoreApp};
@@ -0,0 +1,34 @@
+//! Conversions between the unix timestamps used by the API and [time] types.
+//!
+//! Requires the `time` feature.
+
+use time::{Duration, OffsetDateTime};
+
+/// Converts unix timestamp returned by the API to [OffsetDateTime]
+pub fn from_unix(secs: u32) -> OffsetDateTime {
+    OffsetDateTime::UNIX_EPOCH + Duration::seconds(secs as i64)
+}
+
+/// Converts [OffsetDateTime] to unix timestamp accepted by the API
+///
+/// Dates before 1970 are clamped to the epoch and dates after 2106 to the
+/// last representable second.
+pub fn to_unix(datetime: OffsetDateTime) -> u32 {
+    datetime.unix_timestamp().clamp(0, u32::MAX as i64) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::Month;
+
+    #[test]
+    fn round_trip() {
+        let datetime = from_unix(1332524872);
+        assert_eq!(datetime.year(), 2012);
+        assert_eq!(datetime.month(), Month::March);
+        assert_eq!(datetime.day(), 23);
+        assert_eq!(to_unix(datetime), 1332524872);
+        assert_eq!(to_unix(datetime - Duration::days(365 * 50)), 0);
+    }
+}
@@ -1,9 +1,23 @@
 use std::result::Result as StdResult;
 use crate::error::Error;
-use serde::{de, de::Unexpected, Deserialize, Deserializer};
+use crate::steam_id::SteamID;
+use hyper::StatusCode;
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use serde::{de, de::DeserializeOwned, de::Unexpected, Deserialize, Deserializer};
+use serde_json::Value;
+use std::collections::HashMap;
 use serde_aux::field_attributes::deserialize_default_from_empty_object;
 
 pub const AUTHORITY: &str = "api.steampowered.com";
+pub const COMMUNITY_AUTHORITY: &str = "steamcommunity.com";
+pub const STORE_AUTHORITY: &str = "store.steampowered.com";
+
+/// Characters that are left as is in query values (RFC 3986 unreserved)
+const QUERY_VALUE: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'_')
+    .remove(b'.')
+    .remove(b'~');
 
 pub type Result<T> = StdResult<T, Error>;
 
@@ -37,3 +51,128 @@ where
         )),
     }
 }
+
+pub(crate) fn bool_from_int<'de, D>(deserializer: D) -> StdResult<bool, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match u8::deserialize(deserializer)? {
+        0 => Ok(false),
+        1 => Ok(true),
+        other => Err(de::Error::invalid_value(
+            Unexpected::Unsigned(other as u64),
+            &"zero or one",
+        )),
+    }
+}
+
+pub(crate) fn bool_from_str<'de, D>(deserializer: D) -> StdResult<bool, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = String::deserialize(deserializer)?;
+    match value.as_str() {
+        "0" | "" => Ok(false),
+        "1" => Ok(true),
+        _ => Err(de::Error::invalid_value(
+            Unexpected::Str(&value),
+            &"zero or one in a string",
+        )),
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum IndexedList<T> {
+    List(Vec<T>),
+    Map(HashMap<String, T>),
+    Empty(String),
+}
+
+/// Deserializes lists the API returns as objects keyed by index ("0", "1", ...)
+///
+/// Empty lists are sometimes returned as empty strings.
+pub(crate) fn vec_from_indexed_map<'de, D, T>(deserializer: D) -> StdResult<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    match IndexedList::deserialize(deserializer)? {
+        IndexedList::List(list) => Ok(list),
+        IndexedList::Map(map) => {
+            let mut items: Vec<(usize, T)> = map
+                .into_iter()
+                .map(|(key, item)| (key.parse().unwrap_or(usize::MAX), item))
+                .collect();
+            items.sort_by_key(|(index, _)| *index);
+            Ok(items.into_iter().map(|(_, item)| item).collect())
+        }
+        IndexedList::Empty(value) if value.is_empty() => Ok(Vec::new()),
+        IndexedList::Empty(value) => Err(de::Error::invalid_value(
+            Unexpected::Str(&value),
+            &"a list, an object or an empty string",
+        )),
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum MaybeEmptyArray<T> {
+    Value(T),
+    Empty([(); 0]),
+}
+
+/// Deserializes objects the store returns as empty arrays when they're missing
+pub(crate) fn option_from_empty_array<'de, D, T>(deserializer: D) -> StdResult<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    match Option::<MaybeEmptyArray<T>>::deserialize(deserializer)? {
+        Some(MaybeEmptyArray::Value(value)) => Ok(Some(value)),
+        Some(MaybeEmptyArray::Empty(_)) | None => Ok(None),
+    }
+}
+
+/// Parses the workshop item results with `result` 1, the others are left out
+///
+/// Items that don't exist or aren't visible only have their id and a
+/// different result. Found items that don't parse are returned as errors.
+pub(crate) fn found_results<T: DeserializeOwned>(results: Vec<Value>) -> Result<Vec<T>> {
+    results
+        .into_iter()
+        .filter(|result| result.get("result").and_then(Value::as_u64) == Some(1))
+        .map(|result| Ok(serde_json::from_value(result)?))
+        .collect()
+}
+
+#[cfg(feature = "serde-serialize")]
+pub(crate) fn u64_to_str<S>(value: &u64, serializer: S) -> StdResult<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.collect_str(value)
+}
+
+/// Rejects [SteamIDs](SteamID) that can't belong to any account before
+/// they are sent to the API.
+pub(crate) fn check_ids(ids: &[SteamID]) -> Result<()> {
+    match ids.iter().find(|id| !id.is_valid()) {
+        Some(id) => Err(Error::Client(format!("invalid SteamID: {}", id))),
+        None => Ok(()),
+    }
+}
+
+/// Percent-encodes a value so it can be used in a query string
+pub(crate) fn encode(value: &str) -> String {
+    utf8_percent_encode(value, QUERY_VALUE).to_string()
+}
+
+/// Maps the error statuses of the responses to errors
+pub(crate) fn check_status(status: StatusCode) -> Result<()> {
+    match status {
+        StatusCode::TOO_MANY_REQUESTS => Err(Error::RateLimited),
+        status if !status.is_success() => Err(Error::Client(format!("request failed: {}", status))),
+        _ => Ok(()),
+    }
+}